@@ -1,4 +1,3 @@
-
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -7,4 +6,8 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("number `{0}` does not fit in a usize")]
+    #[diagnostic(code(aoc::invalid_number))]
+    InvalidNumber(String),
 }
\ No newline at end of file
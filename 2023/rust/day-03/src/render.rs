@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+
+use crate::schematic::{NumberId, Schematic, SymbolId};
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_PART: &str = "\x1b[32m";
+const ANSI_NOT_PART: &str = "\x1b[31m";
+const ANSI_GEAR: &str = "\x1b[1;33m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    Ansi,
+    Html,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Highlight {
+    Part,
+    NotPart,
+    Gear,
+}
+
+impl Highlight {
+    fn wrap(&self, text: &str, format: RenderFormat) -> String {
+        match format {
+            RenderFormat::Ansi => {
+                let color = match self {
+                    Highlight::Part => ANSI_PART,
+                    Highlight::NotPart => ANSI_NOT_PART,
+                    Highlight::Gear => ANSI_GEAR,
+                };
+                format!("{color}{text}{ANSI_RESET}")
+            }
+            RenderFormat::Html => {
+                let class = match self {
+                    Highlight::Part => "part",
+                    Highlight::NotPart => "not-part",
+                    Highlight::Gear => "gear",
+                };
+                format!("<span class=\"{class}\">{text}</span>")
+            }
+        }
+    }
+}
+
+fn escape(c: char, format: RenderFormat) -> String {
+    match (format, c) {
+        (RenderFormat::Html, '&') => "&amp;".to_string(),
+        (RenderFormat::Html, '<') => "&lt;".to_string(),
+        (RenderFormat::Html, '>') => "&gt;".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+/// Draws the schematic with part numbers, non-part numbers and gears highlighted
+pub fn render(schematic: &Schematic, format: RenderFormat) -> String {
+    let parts: BTreeSet<NumberId> = schematic.part_numbers().collect();
+    let gears: BTreeSet<SymbolId> = schematic.gears().map(|(id, _)| id).collect();
+
+    let mut lines = vec![vec![".".to_string(); schematic.width()]; schematic.height()];
+
+    schematic
+        .numbers()
+        .iter()
+        .enumerate()
+        .for_each(|(id, number)| {
+            let highlight = if parts.contains(&id) {
+                Highlight::Part
+            } else {
+                Highlight::NotPart
+            };
+            let line = &mut lines[number.line];
+            number.columns().for_each(|col| line[col].clear());
+            // padded back to the original width so leading zeros keep later columns in place
+            let digits = format!("{:0width$}", number.value, width = number.length);
+            line[number.start_index] = highlight.wrap(&digits, format);
+        });
+
+    schematic
+        .symbols()
+        .iter()
+        .enumerate()
+        .for_each(|(id, symbol)| {
+            let text = escape(symbol.character, format);
+            lines[symbol.line][symbol.column] = if gears.contains(&id) {
+                Highlight::Gear.wrap(&text, format)
+            } else {
+                text
+            };
+        });
+
+    let body = lines
+        .iter()
+        .map(|line| line.concat())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    match format {
+        RenderFormat::Ansi => body,
+        RenderFormat::Html => format!("<pre class=\"schematic\">\n{body}\n</pre>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schematic::Connectivity;
+
+    #[test]
+    fn test_render() -> miette::Result<()> {
+        let input = "467..114..
+...*......
+..35..633.";
+        let schematic = Schematic::parse(input, Connectivity::Eight)?;
+
+        let ansi = render(&schematic, RenderFormat::Ansi);
+        assert_eq!(
+            "\x1b[32m467\x1b[0m..\x1b[31m114\x1b[0m..
+...\x1b[1;33m*\x1b[0m......
+..\x1b[32m35\x1b[0m..\x1b[31m633\x1b[0m.",
+            ansi
+        );
+
+        let html = render(&schematic, RenderFormat::Html);
+        assert!(html.starts_with("<pre class=\"schematic\">\n<span class=\"part\">467</span>.."));
+        assert!(html.contains("...<span class=\"gear\">*</span>......"));
+
+        let padded = Schematic::parse("007*.\n.....", Connectivity::Eight)?;
+        assert_eq!(
+            "\x1b[32m007\x1b[0m*.\n.....",
+            render(&padded, RenderFormat::Ansi)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use crate::schematic::Schematic;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolReport {
+    pub character: char,
+    pub symbol_count: usize,
+    pub part_count: usize,
+    pub sum: usize,
+    /// `None` when the product no longer fits in a `u128`
+    pub product: Option<u128>,
+}
+
+impl SymbolReport {
+    fn new(character: char) -> Self {
+        SymbolReport {
+            character,
+            symbol_count: 0,
+            part_count: 0,
+            sum: 0,
+            product: Some(1),
+        }
+    }
+}
+
+/// Groups part numbers by the symbol character they touch. A number touching
+/// several symbols is counted once for each of them.
+pub fn symbol_report(schematic: &Schematic) -> Vec<SymbolReport> {
+    let mut reports: BTreeMap<char, SymbolReport> = BTreeMap::new();
+
+    schematic
+        .symbols()
+        .iter()
+        .enumerate()
+        .for_each(|(symbol_id, symbol)| {
+            let report = reports
+                .entry(symbol.character)
+                .or_insert_with(|| SymbolReport::new(symbol.character));
+            report.symbol_count += 1;
+
+            schematic
+                .numbers_adjacent_to_symbol(symbol_id)
+                .into_iter()
+                .for_each(|number_id| {
+                    let value = schematic.number(number_id).value;
+                    report.part_count += 1;
+                    report.sum += value;
+                    report.product = report
+                        .product
+                        .and_then(|product| product.checked_mul(value as u128));
+                });
+        });
+
+    reports.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schematic::Connectivity;
+
+    #[test]
+    fn test_symbol_report() -> miette::Result<()> {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let schematic = Schematic::parse(input, Connectivity::Eight)?;
+        let report = symbol_report(&schematic);
+
+        let characters: Vec<char> = report.iter().map(|r| r.character).collect();
+        assert_eq!(vec!['#', '$', '*', '+'], characters);
+
+        let star = &report[2];
+        assert_eq!(3, star.symbol_count);
+        assert_eq!(5, star.part_count);
+        assert_eq!(467 + 35 + 617 + 755 + 598, star.sum);
+        assert_eq!(Some(467 * 35 * 617 * 755 * 598), star.product);
+        assert_eq!(4361, report.iter().map(|r| r.sum).sum::<usize>());
+        Ok(())
+    }
+}
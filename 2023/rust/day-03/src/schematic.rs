@@ -0,0 +1,318 @@
+use std::collections::BTreeSet;
+
+use crate::custom_error::AocError;
+
+pub type NumberId = usize;
+pub type SymbolId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Connectivity::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberMeta {
+    pub line: usize,
+    pub start_index: usize,
+    pub value: usize,
+    pub length: usize,
+}
+
+impl NumberMeta {
+    pub fn columns(&self) -> std::ops::Range<usize> {
+        self.start_index..self.start_index + self.length
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub line: usize,
+    pub column: usize,
+    pub character: char,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Number(NumberId),
+    Symbol(SymbolId),
+}
+
+/// Every number span and symbol of an engine schematic, indexed by grid cell
+#[derive(Debug)]
+pub struct Schematic {
+    numbers: Vec<NumberMeta>,
+    symbols: Vec<Symbol>,
+    cells: Vec<Option<Cell>>,
+    width: usize,
+    height: usize,
+    connectivity: Connectivity,
+}
+
+impl Schematic {
+    pub fn parse(input: &str, connectivity: Connectivity) -> Result<Self, AocError> {
+        let lines: Vec<Vec<char>> = input
+            .lines()
+            .map(|line| line.trim_end())
+            .filter(|s| !s.is_empty())
+            .map(|line| line.chars().collect())
+            .collect();
+
+        let height = lines.len();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        let mut schematic = Schematic {
+            numbers: Vec::new(),
+            symbols: Vec::new(),
+            cells: vec![None; width * height],
+            width,
+            height,
+            connectivity,
+        };
+
+        for (line_num, line) in lines.iter().enumerate() {
+            let mut digit_start: Option<usize> = None;
+
+            for (i, c) in line.iter().enumerate() {
+                if c.is_ascii_digit() {
+                    if digit_start.is_none() {
+                        digit_start = Some(i);
+                    }
+                    continue;
+                }
+
+                if let Some(start) = digit_start.take() {
+                    schematic.push_number(&line[start..i], line_num, start)?;
+                }
+
+                if *c != '.' {
+                    schematic.push_symbol(line_num, i, *c);
+                }
+            }
+
+            if let Some(start) = digit_start {
+                schematic.push_number(&line[start..], line_num, start)?;
+            }
+        }
+
+        Ok(schematic)
+    }
+
+    fn push_number(
+        &mut self,
+        digits: &[char],
+        line_num: usize,
+        start: usize,
+    ) -> Result<(), AocError> {
+        let digits: String = digits.iter().collect();
+        let value = digits
+            .parse()
+            .map_err(|_| AocError::InvalidNumber(digits.clone()))?;
+
+        let id = self.numbers.len();
+        self.numbers.push(NumberMeta {
+            line: line_num,
+            start_index: start,
+            length: digits.len(),
+            value,
+        });
+        (start..start + digits.len())
+            .for_each(|col| self.cells[line_num * self.width + col] = Some(Cell::Number(id)));
+        Ok(())
+    }
+
+    fn push_symbol(&mut self, line_num: usize, column: usize, character: char) {
+        let id = self.symbols.len();
+        self.symbols.push(Symbol {
+            line: line_num,
+            column,
+            character,
+        });
+        self.cells[line_num * self.width + column] = Some(Cell::Symbol(id));
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn connectivity(&self) -> Connectivity {
+        self.connectivity
+    }
+
+    pub fn numbers(&self) -> &[NumberMeta] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn number(&self, id: NumberId) -> &NumberMeta {
+        &self.numbers[id]
+    }
+
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        &self.symbols[id]
+    }
+
+    fn cell(&self, line: usize, column: usize) -> Option<Cell> {
+        if line >= self.height || column >= self.width {
+            return None;
+        }
+        self.cells[line * self.width + column]
+    }
+
+    fn neighbour_cells(&self, line: usize, column: usize) -> impl Iterator<Item = Cell> + '_ {
+        self.connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |(dl, dc)| {
+                let line = line.checked_add_signed(*dl)?;
+                let column = column.checked_add_signed(*dc)?;
+                self.cell(line, column)
+            })
+    }
+
+    pub fn symbols_adjacent_to_number(&self, id: NumberId) -> Vec<SymbolId> {
+        let number = &self.numbers[id];
+        let symbols: BTreeSet<SymbolId> = number
+            .columns()
+            .flat_map(|col| self.neighbour_cells(number.line, col))
+            .filter_map(|cell| match cell {
+                Cell::Symbol(symbol_id) => Some(symbol_id),
+                Cell::Number(_) => None,
+            })
+            .collect();
+        symbols.into_iter().collect()
+    }
+
+    pub fn numbers_adjacent_to_symbol(&self, id: SymbolId) -> Vec<NumberId> {
+        let symbol = &self.symbols[id];
+        let numbers: BTreeSet<NumberId> = self
+            .neighbour_cells(symbol.line, symbol.column)
+            .filter_map(|cell| match cell {
+                Cell::Number(number_id) => Some(number_id),
+                Cell::Symbol(_) => None,
+            })
+            .collect();
+        numbers.into_iter().collect()
+    }
+
+    pub fn is_part_number(&self, id: NumberId) -> bool {
+        !self.symbols_adjacent_to_number(id).is_empty()
+    }
+
+    pub fn part_numbers(&self) -> impl Iterator<Item = NumberId> + '_ {
+        (0..self.numbers.len()).filter(|id| self.is_part_number(*id))
+    }
+
+    /// Symbols drawn as `character` that touch exactly `count` numbers, along with those numbers
+    pub fn symbols_with_adjacent_numbers(
+        &self,
+        character: char,
+        count: usize,
+    ) -> impl Iterator<Item = (SymbolId, Vec<NumberId>)> + '_ {
+        self.symbols
+            .iter()
+            .enumerate()
+            .filter(move |(_, symbol)| symbol.character == character)
+            .map(|(id, _)| (id, self.numbers_adjacent_to_symbol(id)))
+            .filter(move |(_, numbers)| numbers.len() == count)
+    }
+
+    pub fn gears(&self) -> impl Iterator<Item = (SymbolId, Vec<NumberId>)> + '_ {
+        self.symbols_with_adjacent_numbers('*', 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_adjacency_queries() -> miette::Result<()> {
+        let schematic = Schematic::parse(INPUT, Connectivity::Eight)?;
+        assert_eq!(10, schematic.numbers().len());
+        assert_eq!(6, schematic.symbols().len());
+
+        let star = schematic
+            .symbols()
+            .iter()
+            .position(|s| s.line == 1 && s.column == 3)
+            .unwrap();
+        let values: Vec<usize> = schematic
+            .numbers_adjacent_to_symbol(star)
+            .into_iter()
+            .map(|id| schematic.number(id).value)
+            .collect();
+        assert_eq!(vec![467, 35], values);
+
+        let not_parts: Vec<usize> = (0..schematic.numbers().len())
+            .filter(|id| !schematic.is_part_number(*id))
+            .map(|id| schematic.number(id).value)
+            .collect();
+        assert_eq!(vec![114, 58], not_parts);
+        Ok(())
+    }
+
+    #[test]
+    fn test_connectivity() -> miette::Result<()> {
+        let input = "1.2
+.#.
+3.4";
+        let eight = Schematic::parse(input, Connectivity::Eight)?;
+        assert_eq!(1, eight.symbols_with_adjacent_numbers('#', 4).count());
+
+        let four = Schematic::parse(input, Connectivity::Four)?;
+        assert_eq!(0, four.part_numbers().count());
+        assert_eq!(1, four.symbols_with_adjacent_numbers('#', 0).count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_unicode_and_overflow() -> miette::Result<()> {
+        let schematic = Schematic::parse("é1*\n...", Connectivity::Eight)?;
+        assert_eq!(1, schematic.number(0).start_index);
+        assert_eq!(vec![0, 1], schematic.symbols_adjacent_to_number(0));
+        assert_eq!('é', schematic.symbol(0).character);
+
+        assert!(matches!(
+            Schematic::parse("99999999999999999999999", Connectivity::Eight),
+            Err(AocError::InvalidNumber(_))
+        ));
+        Ok(())
+    }
+}
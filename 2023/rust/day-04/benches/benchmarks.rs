@@ -19,4 +19,26 @@ fn part2() {
         "../input2.txt",
     )))
     .unwrap();
-}
\ No newline at end of file
+}
+
+#[divan::bench]
+fn winning_count_bitset(bencher: divan::Bencher) {
+    let cards = card::parse_cards(include_str!("../input1.txt")).unwrap();
+    bencher.bench(|| {
+        divan::black_box(&cards)
+            .iter()
+            .map(card::Card::winning_count)
+            .sum::<usize>()
+    });
+}
+
+#[divan::bench]
+fn winning_count_vec(bencher: divan::Bencher) {
+    let cards = card::parse_vec_cards(include_str!("../input1.txt")).unwrap();
+    bencher.bench(|| {
+        divan::black_box(&cards)
+            .iter()
+            .map(card::vec_winning_count)
+            .sum::<usize>()
+    });
+}
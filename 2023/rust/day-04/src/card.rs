@@ -0,0 +1,137 @@
+use crate::custom_error::AocError;
+
+pub const MAX_CARD_NUMBER: u32 = 127;
+
+/// Set of card numbers packed into a single `u128`, one bit per number
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberSet(u128);
+
+impl NumberSet {
+    pub fn insert(&mut self, number: u32) -> Result<(), AocError> {
+        if number > MAX_CARD_NUMBER {
+            return Err(AocError::NumberOutOfRange { number });
+        }
+        self.0 |= 1 << number;
+        Ok(())
+    }
+
+    pub fn contains(&self, number: u32) -> bool {
+        number <= MAX_CARD_NUMBER && self.0 & (1 << number) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn intersection(&self, other: &NumberSet) -> NumberSet {
+        NumberSet(self.0 & other.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    pub winning: NumberSet,
+    pub yours: NumberSet,
+}
+
+impl Card {
+    pub fn winning_count(&self) -> usize {
+        self.winning.intersection(&self.yours).len()
+    }
+}
+
+type WinningNumbers = Vec<u32>;
+type YourNumbers = Vec<u32>;
+pub type VecCard = (WinningNumbers, YourNumbers);
+
+fn split_card(line: &str) -> Result<(&str, &str), AocError> {
+    line.split_once(':')
+        .and_then(|(_, line_data)| line_data.split_once('|'))
+        .ok_or_else(|| AocError::InvalidCard(line.to_string()))
+}
+
+fn parse_numbers(numbers: &str) -> impl Iterator<Item = u32> + '_ {
+    numbers.split(' ').filter_map(|s| s.parse::<u32>().ok())
+}
+
+/// Repeats are rejected, since a set would merge them where a list counts each one
+fn parse_number_set(numbers: &str) -> Result<NumberSet, AocError> {
+    let mut set = NumberSet::default();
+    for number in parse_numbers(numbers) {
+        if set.contains(number) {
+            return Err(AocError::DuplicateNumber { number });
+        }
+        set.insert(number)?;
+    }
+    Ok(set)
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let (w_str, y_str) = split_card(line)?;
+            Ok(Card {
+                winning: parse_number_set(w_str)?,
+                yours: parse_number_set(y_str)?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_vec_cards(input: &str) -> Result<Vec<VecCard>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let (w_str, y_str) = split_card(line)?;
+            Ok((
+                parse_numbers(w_str).collect(),
+                parse_numbers(y_str).collect(),
+            ))
+        })
+        .collect()
+}
+
+pub fn vec_winning_count(card: &VecCard) -> usize {
+    let (w_numbers, y_numbers) = card;
+    y_numbers.iter().filter(|n| w_numbers.contains(n)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitset_matches_vec() -> miette::Result<()> {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let bit_counts: Vec<usize> = parse_cards(input)?
+            .iter()
+            .map(Card::winning_count)
+            .collect();
+        let vec_counts: Vec<usize> = parse_vec_cards(input)?
+            .iter()
+            .map(vec_winning_count)
+            .collect();
+        assert_eq!(vec![4, 2, 2, 1, 0, 0], bit_counts);
+        assert_eq!(vec_counts, bit_counts);
+
+        assert!(matches!(
+            parse_cards("Card 1: 128 | 1"),
+            Err(AocError::NumberOutOfRange { number: 128 })
+        ));
+        assert!(matches!(
+            parse_cards("Card 1: 5 7 | 7 3 7"),
+            Err(AocError::DuplicateNumber { number: 7 })
+        ));
+        Ok(())
+    }
+}
@@ -7,4 +7,28 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid scratchcard: {0}")]
+    #[diagnostic(code(aoc::invalid_card))]
+    InvalidCard(String),
+
+    #[error("card number {number} does not fit in a number set")]
+    #[diagnostic(code(aoc::number_out_of_range))]
+    NumberOutOfRange { number: u32 },
+
+    #[error("copy count of card {card} overflowed")]
+    #[diagnostic(code(aoc::copy_count_overflow))]
+    CopyCountOverflow { card: usize },
+
+    #[error("total number of scratchcards overflowed")]
+    #[diagnostic(code(aoc::card_total_overflow))]
+    CardTotalOverflow,
+
+    #[error("card number {number} appears twice on one side of a card")]
+    #[diagnostic(code(aoc::duplicate_number))]
+    DuplicateNumber { number: u32 },
+
+    #[error("total points of the scratchcards overflowed")]
+    #[diagnostic(code(aoc::point_total_overflow))]
+    PointTotalOverflow,
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+pub mod card;
 pub mod custom_error;
 
 pub mod part1;
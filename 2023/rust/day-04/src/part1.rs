@@ -1,41 +1,18 @@
+use crate::card::parse_cards;
 use crate::custom_error::AocError;
 
-type WinningNumbers = Vec<u32>;
-type YourNumbers = Vec<u32>;
-type Card = (WinningNumbers, YourNumbers);
-
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let normalized_lines: Vec<Card> = input
-        .lines()
-        .map(|line| {
-            let (_, line_data) = line.split_once(':').unwrap();
-            let (w_str, y_str) = line_data.split_once('|').unwrap();
-
-            let w_numbers: WinningNumbers = w_str
-                .split(' ')
-                .filter_map(|s| s.parse::<u32>().ok())
-                .collect();
-
-            let y_numbers: YourNumbers = y_str
-                .split(' ')
-                .filter_map(|s| s.parse::<u32>().ok())
-                .collect();
-
-            (w_numbers, y_numbers)
-        })
-        .collect();
+    let cards = parse_cards(input)?;
 
-    let total: usize = normalized_lines
+    let total = cards
         .iter()
-        .map(|(w_numbers, y_numbers)| {
-            let c = y_numbers.iter().filter(|n| w_numbers.contains(n)).count();
-            if c == 0 {
-                return 0;
-            }
-            1 << (c - 1)
+        .map(|card| match card.winning_count() {
+            0 => Some(0),
+            c => 1u128.checked_shl(c as u32 - 1),
         })
-        .sum();
+        .try_fold(0u128, |total, points| total.checked_add(points?))
+        .ok_or(AocError::PointTotalOverflow)?;
 
     Ok(total.to_string())
 }
@@ -55,4 +32,16 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
         assert_eq!("13", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_many_matches() -> miette::Result<()> {
+        let numbers: Vec<String> = (0..=127).map(|n| n.to_string()).collect();
+        let numbers = numbers.join(" ");
+        let card = format!("Card 1: {numbers} | {numbers}");
+        assert_eq!((1u128 << 127).to_string(), process(&card)?);
+
+        let cards = format!("{card}\n{card}");
+        assert!(matches!(process(&cards), Err(AocError::PointTotalOverflow)));
+        Ok(())
+    }
 }
@@ -1,65 +1,38 @@
+use crate::card::{parse_cards, Card};
 use crate::custom_error::AocError;
 
-type WinningNumbers = Vec<u32>;
-type YourNumbers = Vec<u32>;
-type Card = (WinningNumbers, YourNumbers);
-
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let normalized_lines: Vec<Card> = input
-        .lines()
-        .map(|line| {
-            let (_, line_data) = line.split_once(':').unwrap();
-            let (w_str, y_str) = line_data.split_once('|').unwrap();
-
-            let w_numbers: WinningNumbers = w_str
-                .split(' ')
-                .filter_map(|s| s.parse::<u32>().ok())
-                .collect();
-
-            let y_numbers: YourNumbers = y_str
-                .split(' ')
-                .filter_map(|s| s.parse::<u32>().ok())
-                .collect();
+    let cards = parse_cards(input)?;
 
-            (w_numbers, y_numbers)
-        })
-        .collect();
+    let win_counts: Vec<usize> = cards.iter().map(Card::winning_count).collect();
 
-    let total_cards = normalized_lines.len();
-
-    let card_instances: usize = normalized_lines
+    let total = card_copies(&win_counts)?
         .iter()
-        .enumerate()
-        .map(|(i, c)| count_sub_cards(&normalized_lines, i, c))
-        .sum();
-
-    let total = card_instances + total_cards;
+        .try_fold(0u128, |acc, copies| acc.checked_add(*copies))
+        .ok_or(AocError::CardTotalOverflow)?;
     Ok(total.to_string())
 }
 
-fn card_winning_count(card: &Card) -> usize {
-    let (w_numbers, y_numbers) = card;
-    y_numbers.iter().filter(|n| w_numbers.contains(n)).count()
-}
+/// Number of instances held of each card once every won copy has been processed.
+/// Copies only ever flow to later cards, so a single forward pass is enough.
+pub fn card_copies(win_counts: &[usize]) -> Result<Vec<u128>, AocError> {
+    let mut copies = vec![1u128; win_counts.len()];
 
-fn count_sub_cards(all_cards: &Vec<Card>, index: usize, card: &Card) -> usize {
-    let w_count = card_winning_count(card);
-    if w_count == 0 {
-        0
-    } else {
-        let next_cards: Vec<&Card> = all_cards.iter().skip(index + 1).take(w_count).collect();
-        let total_count: usize = next_cards.len()
-            + next_cards
-                .iter()
-                .enumerate()
-                .map(|(i, c)| {
-                    let next_index = index + 1 + i;
-                    count_sub_cards(all_cards, next_index, c)
-                })
-                .sum::<usize>();
-        total_count
+    for (index, w_count) in win_counts.iter().enumerate() {
+        let current = copies[index];
+        let last = index.saturating_add(*w_count).min(win_counts.len() - 1);
+
+        for (offset, next) in copies[index + 1..=last].iter_mut().enumerate() {
+            *next = next
+                .checked_add(current)
+                .ok_or(AocError::CopyCountOverflow {
+                    card: index + offset + 2,
+                })?;
+        }
     }
+
+    Ok(copies)
 }
 
 #[cfg(test)]
@@ -77,4 +50,15 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
         assert_eq!("30", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_card_copies() -> miette::Result<()> {
+        assert_eq!(vec![1, 2, 4, 8, 14, 1], card_copies(&[4, 2, 2, 1, 0, 0])?);
+        assert!(matches!(
+            card_copies(&[200; 200]),
+            Err(AocError::CopyCountOverflow { card: 129 })
+        ));
+        assert_eq!(vec![1, 2, 4], card_copies(&[usize::MAX, usize::MAX, 0])?);
+        Ok(())
+    }
 }
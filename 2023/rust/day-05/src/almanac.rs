@@ -0,0 +1,551 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::Range;
+
+use crate::custom_error::AocError;
+
+pub type RangeMap = Range<u64>;
+
+/// One `destination source length` line of an almanac map
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataMap {
+    pub input_range: RangeMap,
+    pub output_start: u64,
+}
+
+impl DataMap {
+    pub(crate) fn offset(&self) -> i128 {
+        self.output_start as i128 - self.input_range.start as i128
+    }
+}
+
+impl fmt::Display for DataMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.output_start,
+            self.input_range.start,
+            self.input_range.end - self.input_range.start
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub range: RangeMap,
+    pub offset: i128,
+}
+
+impl Segment {
+    pub fn apply(&self, value: u64) -> u64 {
+        (value as i128 + self.offset) as u64
+    }
+
+    fn image(&self) -> RangeMap {
+        self.apply(self.range.start)..self.apply(self.range.end)
+    }
+}
+
+/// Piecewise translation of values. Segments are sorted, disjoint, never have a
+/// zero offset and never touch a neighbour with the same offset; every value
+/// outside them maps to itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    segments: Vec<Segment>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        PiecewiseMap::default()
+    }
+
+    /// Builds the map for one almanac section. When lines overlap the earlier one
+    /// wins, matching a first-match lookup.
+    pub fn from_data_maps(data_maps: &[DataMap]) -> Self {
+        let mut segments: Vec<Segment> = Vec::new();
+
+        data_maps.iter().for_each(|dm| {
+            let mut uncovered = vec![dm.input_range.clone()];
+            segments.iter().for_each(|covered| {
+                uncovered = uncovered
+                    .iter()
+                    .flat_map(|range| subtract(range, &covered.range))
+                    .collect();
+            });
+            segments.extend(uncovered.into_iter().map(|range| Segment {
+                range,
+                offset: dm.offset(),
+            }));
+        });
+
+        PiecewiseMap::normalized(segments)
+    }
+
+    fn normalized(mut segments: Vec<Segment>) -> Self {
+        segments.retain(|segment| segment.offset != 0 && !segment.range.is_empty());
+        segments.sort_by_key(|segment| segment.range.start);
+
+        let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last)
+                    if last.range.end == segment.range.start && last.offset == segment.offset =>
+                {
+                    last.range.end = segment.range.end;
+                }
+                _ => merged.push(segment),
+            }
+        }
+
+        PiecewiseMap { segments: merged }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Splits the whole domain into consecutive pieces, filling the gaps between
+    /// segments with identity pieces
+    fn pieces(&self) -> Vec<Segment> {
+        let mut pieces = Vec::with_capacity(self.segments.len() * 2 + 1);
+        let mut cursor = 0;
+
+        self.segments.iter().for_each(|segment| {
+            if cursor < segment.range.start {
+                pieces.push(Segment {
+                    range: cursor..segment.range.start,
+                    offset: 0,
+                });
+            }
+            pieces.push(segment.clone());
+            cursor = segment.range.end;
+        });
+
+        if cursor < u64::MAX {
+            pieces.push(Segment {
+                range: cursor..u64::MAX,
+                offset: 0,
+            });
+        }
+
+        pieces
+    }
+
+    fn segment_for(&self, value: u64) -> Option<&Segment> {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.range.end <= value);
+        self.segments
+            .get(index)
+            .filter(|segment| segment.range.contains(&value))
+    }
+
+    pub fn apply(&self, value: u64) -> u64 {
+        match self.segment_for(value) {
+            Some(segment) => segment.apply(value),
+            None => value,
+        }
+    }
+
+    /// The map that applies `self` and then `next`
+    pub fn compose(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let next_pieces = next.pieces();
+        let mut segments = Vec::new();
+
+        self.pieces().iter().for_each(|piece| {
+            let image = piece.image();
+            let first =
+                next_pieces.partition_point(|next_piece| next_piece.range.end <= image.start);
+
+            next_pieces[first..]
+                .iter()
+                .take_while(|next_piece| next_piece.range.start < image.end)
+                .for_each(|next_piece| {
+                    let start = next_piece.range.start.max(image.start);
+                    let end = next_piece.range.end.min(image.end);
+                    segments.push(Segment {
+                        range: (start as i128 - piece.offset) as u64
+                            ..(end as i128 - piece.offset) as u64,
+                        offset: piece.offset + next_piece.offset,
+                    });
+                });
+        });
+
+        PiecewiseMap::normalized(segments)
+    }
+
+    /// Image of `range`, split wherever a different translation applies
+    pub fn apply_range(&self, range: &RangeMap) -> Vec<RangeMap> {
+        self.split_range(range)
+            .iter()
+            .map(|segment| segment.image())
+            .collect()
+    }
+
+    /// Parts of `range` that share a single translation, in input order
+    pub fn split_range(&self, range: &RangeMap) -> Vec<Segment> {
+        self.pieces()
+            .into_iter()
+            .filter(|piece| piece.range.start < range.end && piece.range.end > range.start)
+            .map(|piece| Segment {
+                range: piece.range.start.max(range.start)..piece.range.end.min(range.end),
+                offset: piece.offset,
+            })
+            .collect()
+    }
+
+    /// Every input range whose output lands inside `range`, sorted and merged
+    pub fn preimage(&self, range: &RangeMap) -> Vec<RangeMap> {
+        let mut inputs: Vec<RangeMap> = self
+            .pieces()
+            .iter()
+            .filter_map(|piece| {
+                let image = piece.image();
+                let start = image.start.max(range.start);
+                let end = image.end.min(range.end);
+                (start < end).then(|| {
+                    (start as i128 - piece.offset) as u64..(end as i128 - piece.offset) as u64
+                })
+            })
+            .collect();
+        inputs.sort_by_key(|input| input.start);
+
+        let mut merged: Vec<RangeMap> = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            match merged.last_mut() {
+                Some(last) if last.end == input.start => last.end = input.end,
+                _ => merged.push(input),
+            }
+        }
+        merged
+    }
+
+    /// Input value with the lowest output among `values`, as `(input, output)`
+    pub fn min_over_values(&self, values: &[u64]) -> Option<(u64, u64)> {
+        values
+            .iter()
+            .map(|value| (*value, self.apply(*value)))
+            .min_by_key(|(_, output)| *output)
+    }
+
+    /// Input value with the lowest output among all `ranges`, as `(input, output)`.
+    /// Outputs grow within a piece, so only the start of each split needs checking.
+    pub fn min_over_ranges(&self, ranges: &[RangeMap]) -> Option<(u64, u64)> {
+        ranges
+            .iter()
+            .filter(|range| !range.is_empty())
+            .flat_map(|range| self.split_range(range))
+            .map(|segment| (segment.range.start, segment.apply(segment.range.start)))
+            .min_by_key(|(_, output)| *output)
+    }
+}
+
+pub(crate) fn subtract(range: &RangeMap, other: &RangeMap) -> Vec<RangeMap> {
+    if other.end <= range.start || other.start >= range.end {
+        return vec![range.clone()];
+    }
+
+    let mut remaining = Vec::new();
+    if range.start < other.start {
+        remaining.push(range.start..other.start);
+    }
+    if other.end < range.end {
+        remaining.push(other.end..range.end);
+    }
+    remaining
+}
+
+/// One `X-to-Y map:` section of the almanac
+#[derive(Debug)]
+pub struct CategoryMap {
+    pub source: String,
+    pub target: String,
+    pub data_maps: Vec<DataMap>,
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<CategoryMap>,
+    by_source: BTreeMap<String, usize>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let mut parts = input.split("\n\n");
+        let seeds = get_seeds(parts.next().unwrap_or_default())?;
+        let maps = parts
+            .filter(|part| !part.trim().is_empty())
+            .map(parse_map)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut by_source = BTreeMap::new();
+        for (index, map) in maps.iter().enumerate() {
+            if by_source.insert(map.source.clone(), index).is_some() {
+                return Err(AocError::DuplicateCategory(map.source.clone()));
+            }
+        }
+
+        Ok(Almanac {
+            seeds,
+            maps,
+            by_source,
+        })
+    }
+
+    /// Seeds read as `start length` pairs
+    pub fn seed_ranges(&self) -> Result<Vec<RangeMap>, AocError> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(AocError::InvalidAlmanac(format!(
+                "{} seeds do not pair up into ranges",
+                self.seeds.len()
+            )));
+        }
+
+        self.seeds
+            .chunks(2)
+            .map(|chunk| {
+                let end = chunk[0].checked_add(chunk[1]).ok_or_else(|| {
+                    AocError::InvalidAlmanac(format!(
+                        "seed range {} {} overflows",
+                        chunk[0], chunk[1]
+                    ))
+                })?;
+                Ok(chunk[0]..end)
+            })
+            .collect()
+    }
+
+    /// Sections to apply, in order, to convert `from` values into `to` values
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<&CategoryMap>, AocError> {
+        let mut chain = Vec::new();
+        let mut visited = BTreeSet::from([from]);
+        let mut current = from;
+
+        while current != to {
+            let map = self
+                .by_source
+                .get(current)
+                .map(|index| &self.maps[*index])
+                .ok_or_else(|| AocError::MissingConversion {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    category: current.to_string(),
+                })?;
+
+            if !visited.insert(&map.target) {
+                return Err(AocError::CategoryCycle(map.target.clone()));
+            }
+
+            chain.push(map);
+            current = &map.target;
+        }
+
+        Ok(chain)
+    }
+
+    /// Every section between `from` and `to` folded into a single map
+    pub fn conversion(&self, from: &str, to: &str) -> Result<PiecewiseMap, AocError> {
+        Ok(self
+            .chain(from, to)?
+            .iter()
+            .fold(PiecewiseMap::identity(), |composed, map| {
+                composed.compose(&PiecewiseMap::from_data_maps(&map.data_maps))
+            }))
+    }
+
+    pub fn convert(&self, from: &str, to: &str, value: u64) -> Result<u64, AocError> {
+        Ok(self.conversion(from, to)?.apply(value))
+    }
+
+    /// Converts a range in either direction. When the almanac only maps `to` into
+    /// `from`, the result is every `to` value that lands inside `range`.
+    pub fn convert_range(
+        &self,
+        from: &str,
+        to: &str,
+        range: &RangeMap,
+    ) -> Result<Vec<RangeMap>, AocError> {
+        match self.conversion(from, to) {
+            Ok(conversion) => Ok(conversion.apply_range(range)),
+            Err(AocError::MissingConversion { .. }) => match self.conversion(to, from) {
+                Ok(inverse) => Ok(inverse.preimage(range)),
+                Err(AocError::MissingConversion { .. }) => Err(AocError::MissingConversion {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    category: from.to_string(),
+                }),
+                Err(err) => Err(err),
+            },
+            Err(err) => Err(err),
+        }
+    }
+}
+
+fn get_seeds(input: &str) -> Result<Vec<u64>, AocError> {
+    let (_, seeds_part) = input
+        .split_once(':')
+        .ok_or_else(|| AocError::InvalidAlmanac(format!("missing seeds in `{input}`")))?;
+    Ok(seeds_part
+        .split(' ')
+        .filter_map(|s| s.parse::<u64>().ok())
+        .collect())
+}
+
+fn parse_map(input: &str) -> Result<CategoryMap, AocError> {
+    let mut lines = input.lines();
+    let header = lines.next().unwrap_or_default();
+    let (source, target) = header
+        .strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .ok_or_else(|| AocError::InvalidAlmanac(format!("invalid map header `{header}`")))?;
+
+    let data_maps = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let v: Vec<u64> = line.split(' ').filter_map(|s| s.parse().ok()).collect();
+            let invalid = || AocError::InvalidAlmanac(format!("invalid map line `{line}`"));
+
+            if v.len() != 3 {
+                return Err(invalid());
+            }
+
+            let (output_start, input_start, length) = (v[0], v[1], v[2]);
+            output_start.checked_add(length).ok_or_else(invalid)?;
+
+            Ok(DataMap {
+                input_range: input_start..input_start.checked_add(length).ok_or_else(invalid)?,
+                output_start,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CategoryMap {
+        source: source.to_string(),
+        target: target.to_string(),
+        data_maps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_composed_matches_layers() -> miette::Result<()> {
+        let almanac = Almanac::parse(INPUT)?;
+        let layers: Vec<PiecewiseMap> = almanac
+            .maps
+            .iter()
+            .map(|map| PiecewiseMap::from_data_maps(&map.data_maps))
+            .collect();
+        let composed = almanac.conversion("seed", "location")?;
+
+        (0..120).for_each(|seed| {
+            let stepped = layers.iter().fold(seed, |value, layer| layer.apply(value));
+            assert_eq!(stepped, composed.apply(seed), "seed {seed}");
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_argmin() -> miette::Result<()> {
+        let almanac = Almanac::parse(INPUT)?;
+        let composed = almanac.conversion("seed", "location")?;
+
+        assert_eq!(Some((13, 35)), composed.min_over_values(&almanac.seeds));
+        assert_eq!(
+            Some((82, 46)),
+            composed.min_over_ranges(&almanac.seed_ranges()?)
+        );
+        assert_eq!(
+            Some((10, 10)),
+            PiecewiseMap::identity().min_over_ranges(&[5..5, 10..12])
+        );
+        assert_eq!(None, composed.min_over_ranges(&[7..7, 20..20]));
+
+        let unpaired = Almanac::parse(&INPUT.replace("55 13", "55"))?;
+        assert!(matches!(
+            unpaired.seed_ranges(),
+            Err(AocError::InvalidAlmanac(_))
+        ));
+        let overflowing = Almanac::parse(&INPUT.replace("55 13", "18446744073709551615 1"))?;
+        assert!(matches!(
+            overflowing.seed_ranges(),
+            Err(AocError::InvalidAlmanac(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_category_lookups() -> miette::Result<()> {
+        let almanac = Almanac::parse(INPUT)?;
+
+        assert_eq!(74, almanac.convert("soil", "light", 81)?);
+        assert_eq!(82, almanac.convert("seed", "location", 79)?);
+        assert_eq!(
+            vec![82..83],
+            almanac.convert_range("location", "seed", &(46..47))?
+        );
+
+        let seeds = almanac.convert_range("location", "seed", &(0..35))?;
+        let lowest = almanac.conversion("seed", "location")?;
+        assert!(seeds
+            .iter()
+            .flat_map(|range| range.clone())
+            .all(|seed| lowest.apply(seed) < 35));
+
+        assert!(matches!(
+            almanac.convert("soil", "seed", 1),
+            Err(AocError::MissingConversion { .. })
+        ));
+        let duplicated = INPUT.replace("water-to-light", "soil-to-light");
+        assert!(matches!(
+            Almanac::parse(&duplicated),
+            Err(AocError::DuplicateCategory(category)) if category == "soil"
+        ));
+
+        let cyclic = INPUT.replace("humidity-to-location", "humidity-to-soil");
+        assert!(matches!(
+            Almanac::parse(&cyclic)?.chain("seed", "location"),
+            Err(AocError::CategoryCycle(category)) if category == "soil"
+        ));
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
-use day_05::part1::process;
+use day_05::almanac::Almanac;
+use day_05::part1::{lowest_location, process};
+use day_05::trace::{trace, TraceTarget};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -14,6 +16,28 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input1.txt");
+
+    // `--trace` explains every seed of the input, `--trace 79` or `--trace 79..93` a single one,
+    // followed by the seed that reaches the lowest location
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(flag) = args.iter().position(|arg| arg == "--trace") {
+        let almanac = Almanac::parse(file)?;
+        let targets: Vec<TraceTarget> = match args.get(flag + 1) {
+            Some(target) => vec![target.parse()?],
+            None => almanac
+                .seeds
+                .iter()
+                .map(|seed| TraceTarget::Seed(*seed))
+                .collect(),
+        };
+        for target in targets {
+            println!("{}", trace(&almanac, &target)?);
+        }
+        let (seed, location) = lowest_location(&almanac)?;
+        println!("lowest location {location} from seed {seed}");
+        return Ok(());
+    }
+
     let result = process(file).context("process part 1")?;
     println!("{}", result);
     Ok(())
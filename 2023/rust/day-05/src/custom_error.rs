@@ -7,4 +7,32 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid almanac: {0}")]
+    #[diagnostic(code(aoc::invalid_almanac))]
+    InvalidAlmanac(String),
+
+    #[error("almanac has no seeds")]
+    #[diagnostic(code(aoc::no_seeds))]
+    NoSeeds,
+
+    #[error("no conversion from {from} to {to}: nothing maps {category} values")]
+    #[diagnostic(code(aoc::missing_conversion))]
+    MissingConversion {
+        from: String,
+        to: String,
+        category: String,
+    },
+
+    #[error("category {0} is mapped more than once")]
+    #[diagnostic(code(aoc::duplicate_category))]
+    DuplicateCategory(String),
+
+    #[error("category {0} maps back into itself")]
+    #[diagnostic(code(aoc::category_cycle))]
+    CategoryCycle(String),
+
+    #[error("invalid trace target `{0}`, expected a seed or a `start..end` range")]
+    #[diagnostic(code(aoc::invalid_trace_target))]
+    InvalidTraceTarget(String),
 }
\ No newline at end of file
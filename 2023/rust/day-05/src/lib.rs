@@ -1,4 +1,6 @@
+pub mod almanac;
 pub mod custom_error;
 
 pub mod part1;
 pub mod part2;
+pub mod trace;
@@ -0,0 +1,244 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::almanac::{subtract, Almanac, DataMap, RangeMap};
+use crate::custom_error::AocError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceTarget {
+    Seed(u64),
+    Range(RangeMap),
+}
+
+impl FromStr for TraceTarget {
+    type Err = AocError;
+
+    /// Accepts a single seed (`79`) or a half-open seed range (`79..93`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AocError::InvalidTraceTarget(s.to_string());
+        match s.split_once("..") {
+            Some((start, end)) => Ok(TraceTarget::Range(
+                start.parse().map_err(|_| invalid())?..end.parse().map_err(|_| invalid())?,
+            )),
+            None => Ok(TraceTarget::Seed(s.parse().map_err(|_| invalid())?)),
+        }
+    }
+}
+
+/// A slice of a stage's input that is translated by a single map line, or left as is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceSplit {
+    pub input: RangeMap,
+    pub output: RangeMap,
+    pub matched: Option<DataMap>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStage {
+    pub source: String,
+    pub target: String,
+    pub splits: Vec<TraceSplit>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub target: TraceTarget,
+    pub stages: Vec<TraceStage>,
+}
+
+fn split_stage(data_maps: &[DataMap], range: &RangeMap) -> Vec<TraceSplit> {
+    let mut uncovered = vec![range.clone()];
+    let mut splits = Vec::new();
+
+    data_maps.iter().for_each(|dm| {
+        uncovered = uncovered
+            .iter()
+            .flat_map(|part| {
+                let start = part.start.max(dm.input_range.start);
+                let end = part.end.min(dm.input_range.end);
+                if start < end {
+                    let translate = |value: u64| (value as i128 + dm.offset()) as u64;
+                    splits.push(TraceSplit {
+                        input: start..end,
+                        output: translate(start)..translate(end),
+                        matched: Some(dm.clone()),
+                    });
+                }
+                subtract(part, &dm.input_range)
+            })
+            .collect();
+    });
+
+    splits.extend(uncovered.into_iter().map(|part| TraceSplit {
+        input: part.clone(),
+        output: part,
+        matched: None,
+    }));
+    splits.sort_by_key(|split| split.input.start);
+    splits
+}
+
+/// Follows a seed or seed range through every stage from seed to location
+pub fn trace(almanac: &Almanac, target: &TraceTarget) -> Result<Trace, AocError> {
+    let seeds = match target {
+        TraceTarget::Seed(seed) => {
+            // ranges are half-open, so the last value has no room to be traced on its own
+            let end = seed
+                .checked_add(1)
+                .ok_or_else(|| AocError::InvalidTraceTarget(seed.to_string()))?;
+            *seed..end
+        }
+        TraceTarget::Range(range) => range.clone(),
+    };
+    let mut current = vec![seeds];
+
+    let stages = almanac
+        .chain("seed", "location")?
+        .iter()
+        .map(|map| {
+            let splits: Vec<TraceSplit> = current
+                .iter()
+                .flat_map(|range| split_stage(&map.data_maps, range))
+                .collect();
+            current = splits.iter().map(|split| split.output.clone()).collect();
+
+            TraceStage {
+                source: map.source.clone(),
+                target: map.target.clone(),
+                splits,
+            }
+        })
+        .collect();
+
+    Ok(Trace {
+        target: target.clone(),
+        stages,
+    })
+}
+
+fn write_rule(f: &mut fmt::Formatter<'_>, matched: &Option<DataMap>) -> fmt::Result {
+    match matched {
+        Some(dm) => write!(f, "via `{dm}`"),
+        None => write!(f, "identity"),
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.target {
+            TraceTarget::Seed(seed) => {
+                writeln!(f, "seed {seed}")?;
+                for stage in &self.stages {
+                    let split = &stage.splits[0];
+                    write!(f, "  {} {} ", stage.target, split.output.start)?;
+                    write_rule(f, &split.matched)?;
+                    writeln!(f)?;
+                }
+            }
+            TraceTarget::Range(range) => {
+                writeln!(f, "seed {}..{}", range.start, range.end)?;
+                for stage in &self.stages {
+                    writeln!(f, "  {} -> {}", stage.source, stage.target)?;
+                    for split in &stage.splits {
+                        write!(
+                            f,
+                            "    {}..{} -> {}..{} ",
+                            split.input.start,
+                            split.input.end,
+                            split.output.start,
+                            split.output.end
+                        )?;
+                        write_rule(f, &split.matched)?;
+                        writeln!(f)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_trace_seed() -> miette::Result<()> {
+        let almanac = Almanac::parse(INPUT)?;
+        let trace = trace(&almanac, &"79".parse()?)?;
+        assert_eq!(
+            "seed 79
+  soil 81 via `52 50 48`
+  fertilizer 81 identity
+  water 81 identity
+  light 74 via `18 25 70`
+  temperature 78 via `68 64 13`
+  humidity 78 identity
+  location 82 via `60 56 37`
+",
+            trace.to_string()
+        );
+
+        assert!(matches!(
+            super::trace(&almanac, &TraceTarget::Seed(u64::MAX)),
+            Err(AocError::InvalidTraceTarget(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace_range() -> miette::Result<()> {
+        let almanac = Almanac::parse(INPUT)?;
+        let trace = trace(&almanac, &"79..93".parse()?)?;
+
+        let locations = &trace.stages.last().unwrap().splits;
+        let lowest = locations.iter().map(|split| split.output.start).min();
+        assert_eq!(Some(46), lowest);
+        assert_eq!(
+            14,
+            locations
+                .iter()
+                .map(|split| split.input.end - split.input.start)
+                .sum::<u64>()
+        );
+        assert!(trace
+            .to_string()
+            .contains("    77..88 -> 45..56 via `45 77 23`"));
+        Ok(())
+    }
+}
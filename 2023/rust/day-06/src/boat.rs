@@ -0,0 +1,201 @@
+use crate::custom_error::AocError;
+use crate::race::Race;
+
+/// How a boat turns button time into distance. Every millisecond of charge adds
+/// `acceleration` to the speed, up to `max_charge` milliseconds, and once released
+/// the boat loses `decay` speed per millisecond until it stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boat {
+    pub acceleration: u128,
+    pub max_charge: Option<u128>,
+    pub decay: u128,
+}
+
+impl Default for Boat {
+    fn default() -> Self {
+        Boat {
+            acceleration: 1,
+            max_charge: None,
+            decay: 0,
+        }
+    }
+}
+
+impl Boat {
+    pub fn speed(&self, hold: u128) -> u128 {
+        let charge = match self.max_charge {
+            Some(max_charge) => hold.min(max_charge),
+            None => hold,
+        };
+        self.acceleration.saturating_mul(charge)
+    }
+
+    /// Distance covered when holding for `hold` ms, saturating at `u128::MAX`
+    pub fn distance(&self, race: &Race, hold: u128) -> u128 {
+        if hold >= race.duration {
+            return 0;
+        }
+
+        let speed = self.speed(hold);
+        let travel = race.duration - hold;
+        if self.decay == 0 {
+            return speed.saturating_mul(travel);
+        }
+
+        // speed drops by `decay` every ms, so only the first `moving` ms cover ground
+        let moving = travel.min(speed.div_ceil(self.decay));
+        let lost = moving * moving.saturating_sub(1) / 2;
+        speed
+            .saturating_mul(moving)
+            .saturating_sub(self.decay.saturating_mul(lost))
+    }
+
+    pub fn winning_ways(&self, race: &Race) -> Result<u128, AocError> {
+        if self.decay != 0 {
+            return Ok(self.scan_winning_ways(race));
+        }
+        if self.acceleration == 0 {
+            return Ok(0);
+        }
+
+        // speed * travel beats the record exactly when charge * travel beats record / acceleration
+        let record = race.distance / self.acceleration;
+        let last_hold = race.duration.saturating_sub(1);
+        let charge_limit = self.max_charge.unwrap_or(last_hold).min(last_hold);
+
+        let charging = Race::new(race.duration, record)
+            .winning_range()?
+            .map(|range| {
+                let highest = (*range.end()).min(charge_limit);
+                (highest + 1).saturating_sub((*range.start()).max(1))
+            })
+            .unwrap_or(0);
+
+        // past the charge limit the speed is fixed and the remaining time keeps shrinking
+        let coasting = match self.max_charge {
+            Some(max_charge) if max_charge > 0 && max_charge < last_hold => {
+                let travel_needed = record / max_charge + 1;
+                race.duration
+                    .saturating_sub(travel_needed)
+                    .saturating_sub(max_charge)
+            }
+            _ => 0,
+        };
+
+        Ok(charging + coasting)
+    }
+
+    /// Hold time reaching the furthest, with that distance. Ties keep the shortest hold.
+    pub fn optimal_hold(&self, race: &Race) -> (u128, u128) {
+        if self.decay != 0 {
+            return (0..race.duration.max(1))
+                .map(|hold| (hold, self.distance(race, hold)))
+                .fold(
+                    (0, 0),
+                    |best, current| {
+                        if current.1 > best.1 {
+                            current
+                        } else {
+                            best
+                        }
+                    },
+                );
+        }
+
+        if self.acceleration == 0 || self.max_charge == Some(0) {
+            // the boat never moves, so every hold ties at distance 0
+            return (0, 0);
+        }
+
+        let hold = match self.max_charge {
+            Some(max_charge) => (race.duration / 2).min(max_charge),
+            None => race.duration / 2,
+        };
+        (hold, self.distance(race, hold))
+    }
+
+    fn scan_winning_ways(&self, race: &Race) -> u128 {
+        (1..race.duration)
+            .filter(|hold| self.distance(race, *hold) > race.distance)
+            .count() as u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_boat() -> miette::Result<()> {
+        let boat = Boat::default();
+        let races = [Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)];
+        let ways = races
+            .iter()
+            .map(|race| boat.winning_ways(race))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(vec![4, 8, 9], ways);
+        assert_eq!((3, 12), boat.optimal_hold(&races[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_closed_form_matches_scan() -> miette::Result<()> {
+        for acceleration in 0..4 {
+            for max_charge in [None, Some(0), Some(1), Some(4), Some(9), Some(40)] {
+                let boat = Boat {
+                    acceleration,
+                    max_charge,
+                    decay: 0,
+                };
+                for duration in 0..30 {
+                    for distance in (0..200).step_by(7) {
+                        let race = Race::new(duration, distance);
+                        assert_eq!(
+                            boat.scan_winning_ways(&race),
+                            boat.winning_ways(&race)?,
+                            "{boat:?} {race:?}"
+                        );
+                        let best = (0..duration.max(1))
+                            .map(|hold| boat.distance(&race, hold))
+                            .max();
+                        assert_eq!(best, Some(boat.optimal_hold(&race).1), "{boat:?} {race:?}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decay() -> miette::Result<()> {
+        let boat = Boat {
+            acceleration: 2,
+            max_charge: Some(5),
+            decay: 3,
+        };
+        let race = Race::new(10, 20);
+        // holding 5ms gives speed 10 and then 10 + 7 + 4 + 1 before stopping
+        assert_eq!(22, boat.distance(&race, 5));
+        assert_eq!(22, boat.distance(&race, 6));
+        assert_eq!(21, boat.distance(&race, 7));
+        assert_eq!((5, 22), boat.optimal_hold(&race));
+        assert_eq!(3, boat.winning_ways(&race)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimal_hold_without_movement() {
+        let race = Race::new(10, 20);
+        let stalled = Boat {
+            acceleration: 0,
+            ..Boat::default()
+        };
+        assert_eq!((0, 0), stalled.optimal_hold(&race));
+
+        let uncharged = Boat {
+            max_charge: Some(0),
+            ..Boat::default()
+        };
+        assert_eq!((0, 0), uncharged.optimal_hold(&race));
+    }
+}
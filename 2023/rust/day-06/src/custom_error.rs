@@ -2,9 +2,19 @@
 use miette::Diagnostic;
 use thiserror::Error;
 
+use crate::race::Race;
+
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("race {0:?} is too long to solve in u128")]
+    #[diagnostic(code(aoc::race_overflow))]
+    RaceOverflow(Race),
+
+    #[error("the product of every race's winning ways does not fit in u128")]
+    #[diagnostic(code(aoc::product_overflow))]
+    ProductOverflow,
 }
\ No newline at end of file
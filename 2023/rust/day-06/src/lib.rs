@@ -1,4 +1,6 @@
+pub mod boat;
 pub mod custom_error;
 
 pub mod part1;
 pub mod part2;
+pub mod race;
@@ -1,18 +1,13 @@
 use crate::custom_error::AocError;
-
-#[derive(Debug)]
-struct Race {
-    duration: u32,
-    distance: u32,
-}
+use crate::race::Race;
 
 fn parse(input: &str) -> Vec<Race> {
-    let v: Vec<Vec<u32>> = input
+    let v: Vec<Vec<u128>> = input
         .lines()
         .map(|line| {
             line.split(' ')
-                .filter_map(|c| c.parse::<u32>().ok())
-                .collect::<Vec<u32>>()
+                .filter_map(|c| c.parse::<u128>().ok())
+                .collect::<Vec<u128>>()
         })
         .collect();
     if v.len() != 2 {
@@ -21,30 +16,18 @@ fn parse(input: &str) -> Vec<Race> {
 
     v[0].iter()
         .zip(v[1].iter())
-        .map(|(duration, distance)| Race {
-            duration: duration.to_owned(),
-            distance: distance.to_owned(),
-        })
+        .map(|(duration, distance)| Race::new(*duration, *distance))
         .collect()
 }
 
-fn get_total_ways(previous_race: Race) -> u32 {
-    let mut total_ways = 0;
-    for i in 1..previous_race.duration {
-        let remaining_ms = previous_race.duration - i;
-        let distance_covered = remaining_ms * i;
-        if distance_covered > previous_race.distance {
-            total_ways += 1;
-        }
-    }
-    total_ways
-}
-
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
     let previous_races = parse(input);
 
-    let prod: u32 = previous_races.into_iter().map(get_total_ways).product();
+    let prod = previous_races.iter().try_fold(1u128, |prod, race| {
+        prod.checked_mul(race.winning_ways()?)
+            .ok_or(AocError::ProductOverflow)
+    })?;
     Ok(prod.to_string())
 }
 
@@ -60,4 +43,12 @@ Distance:  9  40  200
         assert_eq!("288", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_product_overflow() {
+        let input = "Time:      100000000000 100000000000 100000000000 100000000000
+Distance:  0 0 0 0
+";
+        assert!(matches!(process(input), Err(AocError::ProductOverflow)));
+    }
 }
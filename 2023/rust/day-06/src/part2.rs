@@ -1,13 +1,8 @@
 use crate::custom_error::AocError;
-
-#[derive(Debug)]
-struct Race {
-    duration: u64,
-    distance: u64,
-}
+use crate::race::Race;
 
 fn parse(input: &str) -> Race {
-    let v: Vec<u64> = input
+    let v: Vec<u128> = input
         .lines()
         .filter_map(|line| {
             let (_, digit_str) = line.split_once(':').unwrap();
@@ -20,36 +15,21 @@ fn parse(input: &str) -> Race {
             //         digit_str.push(c);
             //     }
             // });
-            digit_str.parse::<u64>().ok()
+            digit_str.parse::<u128>().ok()
         })
         .collect();
     if v.len() != 2 {
         panic!("Invalid Data");
     }
 
-    Race {
-        duration: v[0],
-        distance: v[1],
-    }
-}
-
-fn get_total_ways(previous_race: Race) -> u64 {
-    let mut total_ways = 0;
-    for i in 1..previous_race.duration {
-        let remaining_ms = previous_race.duration - i;
-        let distance_covered = remaining_ms * i;
-        if distance_covered > previous_race.distance {
-            total_ways += 1;
-        }
-    }
-    total_ways
+    Race::new(v[0], v[1])
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
     let previous_race = parse(input);
 
-    let prod: u64 = get_total_ways(previous_race);
+    let prod = previous_race.winning_ways()?;
     Ok(prod.to_string())
 }
 
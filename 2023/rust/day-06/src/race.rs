@@ -0,0 +1,128 @@
+use std::ops::RangeInclusive;
+
+use crate::custom_error::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub duration: u128,
+    pub distance: u128,
+}
+
+/// Largest `r` with `r * r <= n`
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from an estimate that is never below the root
+    let mut x = 1u128 << ((128 - n.leading_zeros()).div_ceil(2));
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+impl Race {
+    pub fn new(duration: u128, distance: u128) -> Self {
+        Race { duration, distance }
+    }
+
+    fn travelled(&self, hold: u128) -> u128 {
+        hold * (self.duration - hold)
+    }
+
+    /// Hold times that beat the record, found from the roots of
+    /// `hold * (duration - hold) = distance`. A hold that exactly ties the record
+    /// doesn't count.
+    pub fn winning_range(&self) -> Result<Option<RangeInclusive<u128>>, AocError> {
+        let overflow = || AocError::RaceOverflow(*self);
+        let square = self
+            .duration
+            .checked_mul(self.duration)
+            .ok_or_else(overflow)?;
+        let record = self.distance.checked_mul(4).ok_or_else(overflow)?;
+
+        if square <= record {
+            return Ok(None);
+        }
+
+        // lowest winning hold sits just above (duration - sqrt(discriminant)) / 2
+        let root = isqrt(square - record);
+        let mut lowest = (self.duration - root) / 2;
+        while lowest <= self.duration / 2 && self.travelled(lowest) <= self.distance {
+            lowest += 1;
+        }
+        while lowest > 0 && self.travelled(lowest - 1) > self.distance {
+            lowest -= 1;
+        }
+
+        let highest = self.duration - lowest;
+        if lowest > highest {
+            return Ok(None);
+        }
+        Ok(Some(lowest..=highest))
+    }
+
+    pub fn winning_ways(&self) -> Result<u128, AocError> {
+        Ok(self
+            .winning_range()?
+            .map(|range| range.end() - range.start() + 1)
+            .unwrap_or(0))
+    }
+
+    pub fn winning_ways_brute_force(&self) -> u128 {
+        (1..self.duration)
+            .filter(|hold| self.travelled(*hold) > self.distance)
+            .count() as u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(0, isqrt(0));
+        assert_eq!(3, isqrt(15));
+        assert_eq!(4, isqrt(16));
+        assert_eq!(u64::MAX as u128, isqrt(u128::MAX));
+    }
+
+    #[test]
+    fn test_matches_brute_force() -> miette::Result<()> {
+        // xorshift keeps the test deterministic without pulling in a rng crate
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        };
+
+        for _ in 0..2000 {
+            let duration = next(400) as u128;
+            let best = (duration / 2) * (duration - duration / 2);
+            let distance = next(best as u64 + 2) as u128;
+            let race = Race::new(duration, distance);
+            assert_eq!(
+                race.winning_ways_brute_force(),
+                race.winning_ways()?,
+                "{race:?}"
+            );
+        }
+
+        // 2 * 4 = 8 and 3 * 3 = 9 sit exactly on the record
+        assert_eq!(1, Race::new(6, 8).winning_ways()?);
+        assert_eq!(0, Race::new(6, 9).winning_ways()?);
+        assert_eq!(
+            999_999_999_999_999,
+            Race::new(1_000_000_000_000_000, 0).winning_ways()?
+        );
+        assert!(Race::new(u128::MAX, 1).winning_ways().is_err());
+        Ok(())
+    }
+}
@@ -19,4 +19,77 @@ fn part2() {
         "../input2.txt",
     )))
     .unwrap();
-}
\ No newline at end of file
+}
+
+// The comparator part 1 used before hands carried a packed sort key
+fn cmp_by_position(a: &camel::Hand, b: &camel::Hand) -> std::cmp::Ordering {
+    const CARD_STRENGTH: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+
+    let type_ordering = a.hand_type.cmp(&b.hand_type);
+    if type_ordering != std::cmp::Ordering::Equal {
+        return type_ordering;
+    }
+
+    for i in 0..a.cards.len() {
+        let ac = CARD_STRENGTH.iter().position(|&c| c == a.cards[i]).unwrap();
+        let bc = CARD_STRENGTH.iter().position(|&c| c == b.cards[i]).unwrap();
+
+        let order = ac.cmp(&bc);
+        if order != std::cmp::Ordering::Equal {
+            return order;
+        }
+    }
+
+    std::cmp::Ordering::Equal
+}
+
+#[divan::bench]
+fn sort_by_comparator(bencher: divan::Bencher) {
+    let hands = camel::rank_hands(include_str!("../input1.txt"), &camel::StandardRules).unwrap();
+    bencher
+        .with_inputs(|| hands.clone())
+        .bench_local_values(|mut hands| hands.sort_by(cmp_by_position));
+}
+
+#[divan::bench]
+fn sort_by_key(bencher: divan::Bencher) {
+    let hands = camel::rank_hands(include_str!("../input1.txt"), &camel::StandardRules).unwrap();
+    bencher
+        .with_inputs(|| hands.clone())
+        .bench_local_values(|mut hands| hands.sort_by_key(|hand| hand.key));
+}
+
+fn input_cards() -> Vec<String> {
+    include_str!("../input1.txt")
+        .lines()
+        .map(|line| line[..5].to_string())
+        .collect()
+}
+
+#[divan::bench]
+fn classify_by_counting(bencher: divan::Bencher) {
+    let hands: Vec<Vec<char>> = input_cards()
+        .iter()
+        .map(|cards| cards.chars().collect())
+        .collect();
+    bencher.bench(|| {
+        divan::black_box(&hands)
+            .iter()
+            .map(|cards| camel::calculate_hand_type(cards, &camel::StandardRules))
+            .max()
+    });
+}
+
+#[divan::bench]
+fn classify_by_lookup_table(bencher: divan::Bencher) {
+    let hands = input_cards();
+    let evaluator = camel::HandEvaluator::new(&camel::StandardRules).unwrap();
+    bencher.bench(|| {
+        divan::black_box(&hands)
+            .iter()
+            .map(|cards| evaluator.classify(cards))
+            .max()
+    });
+}
@@ -1,3 +1,5 @@
+use day_07::camel::{rank_hands, StandardRules};
+use day_07::explain::ranked_table;
 use day_07::part1::process;
 use miette::Context;
 
@@ -14,6 +16,12 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input1.txt");
+
+    if std::env::args().any(|arg| arg == "--table") {
+        print!("{}", ranked_table(&rank_hands(file, &StandardRules)?));
+        return Ok(());
+    }
+
     let result = process(file).context("process part 1")?;
     println!("{}", result);
     Ok(())
@@ -1,3 +1,5 @@
+use day_07::camel::{rank_hands, JokerRules};
+use day_07::explain::ranked_table;
 use day_07::part2::process;
 use miette::Context;
 
@@ -14,6 +16,12 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input2.txt");
+
+    if std::env::args().any(|arg| arg == "--table") {
+        print!("{}", ranked_table(&rank_hands(file, &JokerRules)?));
+        return Ok(());
+    }
+
     let result = process(file).context("process part 2")?;
     println!("{}", result);
     Ok(())
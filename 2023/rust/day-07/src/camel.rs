@@ -0,0 +1,540 @@
+use std::cmp::Ordering;
+
+use crate::custom_error::AocError;
+
+const STANDARD_STRENGTH: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+const JOKER_STRENGTH: [char; 13] = [
+    'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+];
+
+#[derive(PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum HandType {
+    HighCard = 1,
+    OnePair = 2,
+    TwoPair = 3,
+    ThreeOfKind = 4,
+    FullHouse = 5,
+    FourOfKind = 6,
+    FiveOfKind = 7,
+}
+
+impl HandType {
+    /// Classifies a hand from its two largest groups of equal cards. Groups of
+    /// more than five, possible with bigger hands, still rank as five of a kind.
+    pub fn from_groups(largest: usize, second: usize) -> Self {
+        match (largest, second) {
+            (5.., _) => HandType::FiveOfKind,
+            (4, _) => HandType::FourOfKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfKind,
+            (2, 2..) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// How hands of the same type are ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// Compare cards in the order they were dealt
+    #[default]
+    DealtOrder,
+    /// Compare the strongest card of each hand first, then the next one, and so on
+    HighestFirst,
+    /// Like `HighestFirst`, but cards from larger groups come first, as in poker
+    GroupsFirst,
+}
+
+pub trait Rules {
+    /// Every valid card, from weakest to strongest
+    fn card_order(&self) -> &[char];
+
+    fn is_wildcard(&self, _card: char) -> bool {
+        false
+    }
+
+    fn hand_size(&self) -> usize {
+        5
+    }
+
+    fn tie_break(&self) -> TieBreak {
+        TieBreak::DealtOrder
+    }
+
+    fn strength(&self, card: char) -> Option<usize> {
+        self.card_order().iter().position(|&c| c == card)
+    }
+}
+
+/// Part 1 rules: no wildcards, `J` is a jack
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardRules;
+
+impl Rules for StandardRules {
+    fn card_order(&self) -> &[char] {
+        &STANDARD_STRENGTH
+    }
+}
+
+/// Part 2 rules: `J` is a joker, the weakest card but wild when picking the hand type
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JokerRules;
+
+impl Rules for JokerRules {
+    fn card_order(&self) -> &[char] {
+        &JOKER_STRENGTH
+    }
+
+    fn is_wildcard(&self, card: char) -> bool {
+        card == 'J'
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomRules {
+    pub card_order: Vec<char>,
+    pub wildcards: Vec<char>,
+    pub hand_size: usize,
+    pub tie_break: TieBreak,
+}
+
+impl Rules for CustomRules {
+    fn card_order(&self) -> &[char] {
+        &self.card_order
+    }
+
+    fn is_wildcard(&self, card: char) -> bool {
+        self.wildcards.contains(&card)
+    }
+
+    fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+}
+
+fn classify_counts(repetition_index: &mut [usize], wildcards: usize) -> HandType {
+    repetition_index.sort_unstable_by(|a, b| b.cmp(a));
+    let largest = repetition_index.first().copied().unwrap_or(0) + wildcards;
+    let second = repetition_index.get(1).copied().unwrap_or(0);
+
+    HandType::from_groups(largest, second)
+}
+
+/// Picks the hand type, counting every wildcard towards the largest group
+pub fn calculate_hand_type<R: Rules + ?Sized>(cards: &[char], rules: &R) -> HandType {
+    let mut repetition_index = vec![0; rules.card_order().len()];
+    let mut wildcards = 0;
+
+    cards.iter().for_each(|&c| {
+        if rules.is_wildcard(c) {
+            wildcards += 1;
+        } else if let Some(strength) = rules.strength(c) {
+            repetition_index[strength] += 1;
+        }
+    });
+
+    classify_counts(&mut repetition_index, wildcards)
+}
+
+/// How the wildcards of a hand were counted when picking its type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WildcardResolution {
+    pub wildcards: Vec<char>,
+    /// Card the wildcards joined, `None` when every card is wild
+    pub joined: Option<char>,
+    /// Type the hand would have if its wildcards were ordinary cards
+    pub natural: HandType,
+}
+
+/// `None` when the hand holds no wildcard
+pub fn resolve_wildcards<R: Rules + ?Sized>(
+    cards: &[char],
+    rules: &R,
+) -> Option<WildcardResolution> {
+    let wildcards: Vec<char> = cards
+        .iter()
+        .copied()
+        .filter(|c| rules.is_wildcard(*c))
+        .collect();
+    if wildcards.is_empty() {
+        return None;
+    }
+
+    let mut repetition_index = vec![0; rules.card_order().len()];
+    cards
+        .iter()
+        .filter_map(|c| rules.strength(*c))
+        .for_each(|strength| repetition_index[strength] += 1);
+
+    // the largest group takes the wildcards; between equal groups the stronger card wins
+    let joined = cards
+        .iter()
+        .filter(|c| !rules.is_wildcard(**c))
+        .max_by_key(|c| {
+            let strength = rules.strength(**c).unwrap_or(0);
+            (repetition_index[strength], strength)
+        })
+        .copied();
+
+    Some(WildcardResolution {
+        wildcards,
+        joined,
+        natural: classify_counts(&mut repetition_index, 0),
+    })
+}
+
+pub fn bits_per_card<R: Rules + ?Sized>(rules: &R) -> u32 {
+    usize::BITS - rules.card_order().len().saturating_sub(1).leading_zeros()
+}
+
+/// Packs the hand type and the tie-break strengths into one integer, so that
+/// comparing keys orders hands exactly like `Hand::cmp_with`
+pub fn sort_key<R: Rules + ?Sized>(
+    hand_type: HandType,
+    strengths: &[usize],
+    rules: &R,
+) -> Result<u64, AocError> {
+    pack_key(hand_type as u64, 3, strengths, bits_per_card(rules)).ok_or(AocError::KeyTooWide {
+        cards: rules.card_order().len(),
+        hand_size: strengths.len(),
+    })
+}
+
+/// Appends `bits` wide strengths after a `prefix_bits` wide prefix, `None` if
+/// they don't fit in 64 bits
+pub fn pack_key(prefix: u64, prefix_bits: u32, strengths: &[usize], bits: u32) -> Option<u64> {
+    if prefix_bits as usize + bits as usize * strengths.len() > u64::BITS as usize {
+        return None;
+    }
+
+    Some(
+        strengths
+            .iter()
+            .fold(prefix, |key, strength| key << bits | *strength as u64),
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<char>,
+    pub bid: u32,
+    pub hand_type: HandType,
+    pub key: u64,
+    /// Cards in the order ties are broken
+    pub ordered_cards: Vec<char>,
+    pub wildcards: Option<WildcardResolution>,
+}
+
+impl Hand {
+    pub fn parse<R: Rules + ?Sized>(input: &str, rules: &R) -> Result<Self, AocError> {
+        let (cards_part, bid_part) = input
+            .split_once(' ')
+            .ok_or_else(|| AocError::InvalidHand(input.to_string()))?;
+        let cards: Vec<char> = cards_part.chars().collect();
+
+        if cards.len() != rules.hand_size() {
+            return Err(AocError::WrongHandSize {
+                hand: cards_part.to_string(),
+                expected: rules.hand_size(),
+            });
+        }
+        if let Some(card) = cards.iter().find(|c| rules.strength(**c).is_none()) {
+            return Err(AocError::UnknownCard {
+                card: *card,
+                hand: cards_part.to_string(),
+            });
+        }
+
+        let hand_type = calculate_hand_type(&cards, rules);
+        let ordered_cards = tie_break_order(&cards, rules);
+        let strengths: Vec<usize> = ordered_cards
+            .iter()
+            .filter_map(|c| rules.strength(*c))
+            .collect();
+
+        Ok(Self {
+            hand_type,
+            key: sort_key(hand_type, &strengths, rules)?,
+            ordered_cards,
+            wildcards: resolve_wildcards(&cards, rules),
+            cards,
+            bid: bid_part
+                .trim()
+                .parse()
+                .map_err(|_| AocError::InvalidHand(input.to_string()))?,
+        })
+    }
+
+    /// Card strengths in the order the rules compare them
+    pub fn tie_break_strengths<R: Rules + ?Sized>(&self, rules: &R) -> Vec<usize> {
+        self.ordered_cards
+            .iter()
+            .filter_map(|c| rules.strength(*c))
+            .collect()
+    }
+
+    pub fn cmp_with<R: Rules + ?Sized>(&self, other: &Hand, rules: &R) -> Ordering {
+        self.hand_type.cmp(&other.hand_type).then_with(|| {
+            self.tie_break_strengths(rules)
+                .cmp(&other.tie_break_strengths(rules))
+        })
+    }
+}
+
+/// Cards in the order the rules compare them
+pub fn tie_break_order<R: Rules + ?Sized>(cards: &[char], rules: &R) -> Vec<char> {
+    let mut ordered = cards.to_vec();
+    match rules.tie_break() {
+        TieBreak::DealtOrder => {}
+        TieBreak::HighestFirst => {
+            ordered.sort_by_key(|c| std::cmp::Reverse(rules.strength(*c)));
+        }
+        TieBreak::GroupsFirst => {
+            let group = |card: &char| cards.iter().filter(|c| *c == card).count();
+            ordered.sort_by_key(|c| std::cmp::Reverse((group(c), rules.strength(*c))));
+        }
+    }
+    ordered
+}
+
+/// Hands from the input, weakest first
+pub fn rank_hands<R: Rules + ?Sized>(input: &str, rules: &R) -> Result<Vec<Hand>, AocError> {
+    let mut hands = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| Hand::parse(line, rules))
+        .collect::<Result<Vec<Hand>, AocError>>()?;
+
+    hands.sort_by_key(|hand| hand.key);
+    Ok(hands)
+}
+
+pub fn total_winnings<R: Rules + ?Sized>(input: &str, rules: &R) -> Result<u64, AocError> {
+    Ok(rank_hands(input, rules)?
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| hand.bid as u64 * (i as u64 + 1))
+        .sum())
+}
+
+/// Hand type of every possible hand, indexed by card strengths read as digits
+/// in base `card_order().len()`
+#[derive(Debug, Clone)]
+pub struct HandEvaluator {
+    strengths: [u8; 128],
+    radix: usize,
+    hand_size: usize,
+    table: Vec<HandType>,
+}
+
+impl HandEvaluator {
+    pub const MAX_TABLE_SIZE: usize = 1 << 24;
+
+    pub fn new<R: Rules + ?Sized>(rules: &R) -> Result<Self, AocError> {
+        let card_order = rules.card_order();
+        let radix = card_order.len();
+        let hand_size = rules.hand_size();
+        let too_large = || AocError::EvaluatorTooLarge {
+            cards: radix,
+            hand_size,
+        };
+
+        let size = u32::try_from(hand_size)
+            .ok()
+            .and_then(|hand_size| radix.checked_pow(hand_size))
+            .filter(|size| *size <= Self::MAX_TABLE_SIZE)
+            .ok_or_else(too_large)?;
+
+        let mut strengths = [u8::MAX; 128];
+        for (strength, card) in card_order.iter().enumerate() {
+            if !card.is_ascii() || radix > u8::MAX as usize {
+                return Err(too_large());
+            }
+            strengths[*card as usize] = strength as u8;
+        }
+
+        let wild: Vec<bool> = card_order.iter().map(|c| rules.is_wildcard(*c)).collect();
+        let mut repetition_index = vec![0; radix];
+        let table = (0..size)
+            .map(|index| {
+                repetition_index.iter_mut().for_each(|count| *count = 0);
+                let mut wildcards = 0;
+                let mut rest = index;
+                for _ in 0..hand_size {
+                    let strength = rest % radix;
+                    rest /= radix;
+                    if wild[strength] {
+                        wildcards += 1;
+                    } else {
+                        repetition_index[strength] += 1;
+                    }
+                }
+                classify_counts(&mut repetition_index, wildcards)
+            })
+            .collect();
+
+        Ok(HandEvaluator {
+            strengths,
+            radix,
+            hand_size,
+            table,
+        })
+    }
+
+    /// `None` when the hand has the wrong size or an unknown card
+    pub fn classify(&self, cards: &str) -> Option<HandType> {
+        if cards.len() != self.hand_size {
+            return None;
+        }
+
+        let mut index = 0;
+        for card in cards.bytes() {
+            let strength = *self.strengths.get(card as usize)?;
+            if strength == u8::MAX {
+                return None;
+            }
+            index = index * self.radix + strength as usize;
+        }
+        Some(self.table[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn test_builtin_rules() -> miette::Result<()> {
+        assert_eq!(6440, total_winnings(INPUT, &StandardRules)?);
+        assert_eq!(5905, total_winnings(INPUT, &JokerRules)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_rules() -> miette::Result<()> {
+        let rules = CustomRules {
+            card_order: "J2Q3456789TKA".chars().collect(),
+            wildcards: vec!['J', 'Q'],
+            hand_size: 6,
+            tie_break: TieBreak::HighestFirst,
+        };
+
+        let hand = Hand::parse("2JQ345 1", &rules)?;
+        assert_eq!(HandType::ThreeOfKind, hand.hand_type);
+        assert_eq!(
+            HandType::FiveOfKind,
+            Hand::parse("JQ2222 1", &rules)?.hand_type
+        );
+
+        let ranked = rank_hands("23456A 1\nA23456 2\n99KK33 3", &rules)?;
+        assert_eq!(
+            vec![1, 2, 3],
+            ranked.iter().map(|h| h.bid).collect::<Vec<_>>()
+        );
+        assert_eq!(Ordering::Equal, ranked[0].cmp_with(&ranked[1], &rules));
+
+        assert!(matches!(
+            Hand::parse(
+                "23456789TKA23456 1",
+                &CustomRules {
+                    hand_size: 16,
+                    ..rules.clone()
+                }
+            ),
+            Err(AocError::KeyTooWide { .. })
+        ));
+        assert!(matches!(
+            Hand::parse("2345 1", &rules),
+            Err(AocError::WrongHandSize { expected: 6, .. })
+        ));
+        assert!(matches!(
+            Hand::parse("23456X 1", &rules),
+            Err(AocError::UnknownCard { card: 'X', .. })
+        ));
+        Ok(())
+    }
+
+    /// Every hand type under both rule sets, with jokers completing each kind of group
+    /// and pairs of hands that only the tie break separates
+    const HANDS: [(&str, HandType, HandType); 18] = [
+        ("AAAAA", HandType::FiveOfKind, HandType::FiveOfKind),
+        ("JJJJJ", HandType::FiveOfKind, HandType::FiveOfKind),
+        ("JAAAJ", HandType::FullHouse, HandType::FiveOfKind),
+        ("AA8AA", HandType::FourOfKind, HandType::FourOfKind),
+        ("8AAAA", HandType::FourOfKind, HandType::FourOfKind),
+        ("J3332", HandType::ThreeOfKind, HandType::FourOfKind),
+        ("22JJ3", HandType::TwoPair, HandType::FourOfKind),
+        ("23332", HandType::FullHouse, HandType::FullHouse),
+        ("2233J", HandType::TwoPair, HandType::FullHouse),
+        ("TTT98", HandType::ThreeOfKind, HandType::ThreeOfKind),
+        ("J3432", HandType::OnePair, HandType::ThreeOfKind),
+        ("2JJ45", HandType::OnePair, HandType::ThreeOfKind),
+        ("23432", HandType::TwoPair, HandType::TwoPair),
+        ("A23A4", HandType::OnePair, HandType::OnePair),
+        ("2345J", HandType::HighCard, HandType::OnePair),
+        ("23456", HandType::HighCard, HandType::HighCard),
+        ("65432", HandType::HighCard, HandType::HighCard),
+        ("T55J5", HandType::ThreeOfKind, HandType::FourOfKind),
+    ];
+
+    fn hands_input() -> String {
+        HANDS
+            .iter()
+            .enumerate()
+            .map(|(bid, (cards, _, _))| format!("{cards} {}\n", bid + 1))
+            .collect()
+    }
+
+    fn expected_type(cards: &[char], rules: &dyn Rules) -> HandType {
+        let cards: String = cards.iter().collect();
+        let (_, standard, joker) = HANDS.iter().find(|(hand, _, _)| *hand == cards).unwrap();
+        if !rules.is_wildcard('J') {
+            *standard
+        } else {
+            *joker
+        }
+    }
+
+    #[test]
+    fn test_sort_key_matches_comparator() -> miette::Result<()> {
+        let input = hands_input();
+        for rules in [&StandardRules as &dyn Rules, &JokerRules] {
+            let hands = rank_hands(&input, rules)?;
+            assert_eq!(HANDS.len(), hands.len());
+            assert!(hands
+                .windows(2)
+                .all(|pair| pair[0].cmp_with(&pair[1], rules) == Ordering::Less));
+            for hand in &hands {
+                assert_eq!(expected_type(&hand.cards, rules), hand.hand_type);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluator() -> miette::Result<()> {
+        for rules in [&StandardRules as &dyn Rules, &JokerRules] {
+            let evaluator = HandEvaluator::new(rules)?;
+            for (cards, _, _) in HANDS {
+                let expected = expected_type(&cards.chars().collect::<Vec<char>>(), rules);
+                assert_eq!(Some(expected), evaluator.classify(cards));
+            }
+            assert_eq!(None, evaluator.classify("2345X"));
+            assert_eq!(None, evaluator.classify("2345"));
+        }
+        Ok(())
+    }
+}
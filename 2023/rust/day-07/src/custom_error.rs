@@ -7,4 +7,32 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid hand `{0}`, expected cards and a bid")]
+    #[diagnostic(code(aoc::invalid_hand))]
+    InvalidHand(String),
+
+    #[error("hand {hand} should have {expected} cards")]
+    #[diagnostic(code(aoc::wrong_hand_size))]
+    WrongHandSize { hand: String, expected: usize },
+
+    #[error("unknown card {card} in hand {hand}")]
+    #[diagnostic(code(aoc::unknown_card))]
+    UnknownCard { card: char, hand: String },
+
+    #[error("{hand_size} cards out of {cards} don't fit in a 64 bit sort key")]
+    #[diagnostic(code(aoc::key_too_wide))]
+    KeyTooWide { cards: usize, hand_size: usize },
+
+    #[error("a lookup table for {hand_size} cards out of {cards} is too large")]
+    #[diagnostic(code(aoc::evaluator_too_large))]
+    EvaluatorTooLarge { cards: usize, hand_size: usize },
+
+    #[error("invalid poker card `{0}`, expected a rank and a suit like `AS`")]
+    #[diagnostic(code(aoc::invalid_poker_card))]
+    InvalidPokerCard(String),
+
+    #[error("card {0} is dealt more than once")]
+    #[diagnostic(code(aoc::duplicate_poker_card))]
+    DuplicatePokerCard(String),
 }
\ No newline at end of file
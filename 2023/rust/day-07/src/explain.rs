@@ -0,0 +1,190 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::camel::{Hand, HandType, WildcardResolution};
+
+/// The rule that decided how two hands are ordered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    HandType {
+        ours: HandType,
+        theirs: HandType,
+    },
+    /// First differing card, `position` counting in tie-break order
+    Card {
+        position: usize,
+        ours: char,
+        theirs: char,
+    },
+    Identical,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub ours: String,
+    pub theirs: String,
+    pub ordering: Ordering,
+    pub reason: Reason,
+    pub our_wildcards: Option<WildcardResolution>,
+    pub their_wildcards: Option<WildcardResolution>,
+}
+
+impl Hand {
+    pub fn explain_vs(&self, other: &Hand) -> Explanation {
+        let reason = if self.hand_type != other.hand_type {
+            Reason::HandType {
+                ours: self.hand_type,
+                theirs: other.hand_type,
+            }
+        } else {
+            self.ordered_cards
+                .iter()
+                .zip(other.ordered_cards.iter())
+                .enumerate()
+                .find(|(_, (ours, theirs))| ours != theirs)
+                .map(|(position, (ours, theirs))| Reason::Card {
+                    position,
+                    ours: *ours,
+                    theirs: *theirs,
+                })
+                .unwrap_or(Reason::Identical)
+        };
+
+        Explanation {
+            ours: self.cards.iter().collect(),
+            theirs: other.cards.iter().collect(),
+            ordering: self.key.cmp(&other.key),
+            reason,
+            our_wildcards: self.wildcards.clone(),
+            their_wildcards: other.wildcards.clone(),
+        }
+    }
+}
+
+impl fmt::Display for WildcardResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wildcards: String = self.wildcards.iter().collect();
+        match self.joined {
+            Some(card) => write!(f, "{wildcards} as {card}")?,
+            None => write!(f, "{wildcards} all wild")?,
+        }
+        write!(f, " ({:?} without them)", self.natural)
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self.ordering {
+            Ordering::Less => "loses to",
+            Ordering::Equal => "ties with",
+            Ordering::Greater => "beats",
+        };
+        write!(f, "{} {verdict} {}: ", self.ours, self.theirs)?;
+
+        match &self.reason {
+            Reason::HandType { ours, theirs } => write!(f, "{ours:?} vs {theirs:?}")?,
+            Reason::Card {
+                position,
+                ours,
+                theirs,
+            } => write!(f, "same type, card {} is {ours} vs {theirs}", position + 1)?,
+            Reason::Identical => write!(f, "same type and cards")?,
+        }
+
+        for (cards, wildcards) in [
+            (&self.ours, &self.our_wildcards),
+            (&self.theirs, &self.their_wildcards),
+        ] {
+            if let Some(wildcards) = wildcards {
+                write!(f, "; {cards} plays {wildcards}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One line per hand with its rank, type, bid and winnings. `hands` must already
+/// be ranked, weakest first.
+pub fn ranked_table(hands: &[Hand]) -> String {
+    let mut table = format!(
+        "{:>5}  {:<6} {:<12} {:>5} {:>10}  {}\n",
+        "rank", "hand", "type", "bid", "winnings", "wildcards"
+    );
+
+    hands.iter().enumerate().for_each(|(i, hand)| {
+        let rank = i as u64 + 1;
+        let cards: String = hand.cards.iter().collect();
+        let wildcards = hand
+            .wildcards
+            .as_ref()
+            .map(|wildcards| wildcards.to_string())
+            .unwrap_or_default();
+        table.push_str(
+            format!(
+                "{rank:>5}  {cards:<6} {:<12} {:>5} {:>10}  {wildcards}",
+                format!("{:?}", hand.hand_type),
+                hand.bid,
+                hand.bid as u64 * rank
+            )
+            .trim_end(),
+        );
+        table.push('\n');
+    });
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camel::{rank_hands, JokerRules, StandardRules};
+
+    const INPUT: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn test_explain_vs() -> miette::Result<()> {
+        let hands = rank_hands(INPUT, &StandardRules)?;
+        let (ktjjt, kk677) = (&hands[1], &hands[2]);
+        assert_eq!(
+            "KK677 beats KTJJT: same type, card 2 is K vs T",
+            kk677.explain_vs(ktjjt).to_string()
+        );
+        assert_eq!(
+            "32T3K loses to KTJJT: OnePair vs TwoPair",
+            hands[0].explain_vs(ktjjt).to_string()
+        );
+
+        let hands = rank_hands(INPUT, &JokerRules)?;
+        let (qqqja, ktjjt) = (&hands[3], &hands[4]);
+        assert_eq!(
+            Reason::Card {
+                position: 0,
+                ours: 'K',
+                theirs: 'Q'
+            },
+            ktjjt.explain_vs(qqqja).reason
+        );
+        assert_eq!(
+            "KTJJT beats QQQJA: same type, card 1 is K vs Q; KTJJT plays JJ as T (TwoPair without them); QQQJA plays J as Q (ThreeOfKind without them)",
+            ktjjt.explain_vs(qqqja).to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ranked_table() -> miette::Result<()> {
+        let table = ranked_table(&rank_hands(INPUT, &JokerRules)?);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(6, lines.len());
+        assert_eq!("    1  32T3K  OnePair        765        765", lines[1]);
+        assert_eq!(
+            "    5  KTJJT  FourOfKind     220       1100  JJ as T (TwoPair without them)",
+            lines[5]
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+pub mod camel;
 pub mod custom_error;
+pub mod explain;
+pub mod poker;
 
 pub mod part1;
 pub mod part2;
@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::camel::{
+    bits_per_card, calculate_hand_type, pack_key, tie_break_order, HandType, Rules, StandardRules,
+    TieBreak,
+};
+use crate::custom_error::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+/// A card written as rank then suit, e.g. `AS` or `Td`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PokerCard {
+    pub rank: char,
+    pub suit: Suit,
+}
+
+impl FromStr for PokerCard {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AocError::InvalidPokerCard(s.to_string());
+        let mut chars = s.chars();
+        let (Some(rank), Some(suit), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(invalid());
+        };
+
+        let suit = match suit.to_ascii_uppercase() {
+            'C' => Suit::Clubs,
+            'D' => Suit::Diamonds,
+            'H' => Suit::Hearts,
+            'S' => Suit::Spades,
+            _ => return Err(invalid()),
+        };
+        let rank = rank.to_ascii_uppercase();
+        PokerRules.strength(rank).ok_or_else(invalid)?;
+
+        Ok(PokerCard { rank, suit })
+    }
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<PokerCard>, AocError> {
+    input.split_whitespace().map(str::parse).collect()
+}
+
+/// Camel Cards ranks without wildcards, ties broken poker style
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PokerRules;
+
+impl Rules for PokerRules {
+    fn card_order(&self) -> &[char] {
+        StandardRules.card_order()
+    }
+
+    fn tie_break(&self) -> TieBreak {
+        TieBreak::GroupsFirst
+    }
+}
+
+#[derive(PartialOrd, Ord, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum PokerCategory {
+    HighCard = 1,
+    OnePair = 2,
+    TwoPair = 3,
+    ThreeOfKind = 4,
+    Straight = 5,
+    Flush = 6,
+    FullHouse = 7,
+    FourOfKind = 8,
+    StraightFlush = 9,
+    FiveOfKind = 10,
+}
+
+impl From<HandType> for PokerCategory {
+    fn from(hand_type: HandType) -> Self {
+        match hand_type {
+            HandType::HighCard => PokerCategory::HighCard,
+            HandType::OnePair => PokerCategory::OnePair,
+            HandType::TwoPair => PokerCategory::TwoPair,
+            HandType::ThreeOfKind => PokerCategory::ThreeOfKind,
+            HandType::FullHouse => PokerCategory::FullHouse,
+            HandType::FourOfKind => PokerCategory::FourOfKind,
+            HandType::FiveOfKind => PokerCategory::FiveOfKind,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PokerHand {
+    pub cards: Vec<PokerCard>,
+    pub category: PokerCategory,
+    /// Comparing keys orders hands by category, then standard tie-breaking
+    pub key: u64,
+}
+
+/// Rank strength of the highest card of a straight, counting the ace low in A-2-3-4-5
+fn straight_high(strengths: &[usize]) -> Option<usize> {
+    let mut distinct = strengths.to_vec();
+    distinct.sort_unstable_by(|a, b| b.cmp(a));
+    distinct.dedup();
+
+    match distinct[..] {
+        [high, .., low] if distinct.len() == 5 && high - low == 4 => Some(high),
+        [12, 3, 2, 1, 0] => Some(3),
+        _ => None,
+    }
+}
+
+impl PokerHand {
+    pub fn new(cards: &[PokerCard]) -> Result<Self, AocError> {
+        if cards.len() != 5 {
+            return Err(AocError::WrongHandSize {
+                hand: cards.iter().map(|card| card.rank).collect(),
+                expected: 5,
+            });
+        }
+        check_unique(cards)?;
+
+        let ranks: Vec<char> = cards.iter().map(|card| card.rank).collect();
+        let mut strengths: Vec<usize> = tie_break_order(&ranks, &PokerRules)
+            .iter()
+            .filter_map(|rank| PokerRules.strength(*rank))
+            .collect();
+
+        let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+        let straight = straight_high(&strengths);
+        let category = match (straight, flush) {
+            (Some(_), true) => PokerCategory::StraightFlush,
+            (Some(_), false) => PokerCategory::Straight,
+            (None, true) => PokerCategory::Flush,
+            (None, false) => PokerCategory::HighCard,
+        }
+        .max(calculate_hand_type(&ranks, &PokerRules).into());
+
+        if let Some(high) = straight {
+            // only the top card matters between straights, and the wheel tops out at 5
+            strengths = vec![high, 0, 0, 0, 0];
+        }
+
+        Ok(PokerHand {
+            cards: cards.to_vec(),
+            category,
+            key: pack_key(category as u64, 4, &strengths, bits_per_card(&PokerRules))
+                .expect("five poker cards fit in a 64 bit key"),
+        })
+    }
+
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        PokerHand::new(&parse_cards(input)?)
+    }
+
+    /// Strongest five card hand that can be made from `cards`
+    pub fn best_of(cards: &[PokerCard]) -> Result<Self, AocError> {
+        check_unique(cards)?;
+        let hands = cards
+            .iter()
+            .copied()
+            .combinations(5)
+            .map(|hand| PokerHand::new(&hand))
+            .collect::<Result<Vec<PokerHand>, AocError>>()?;
+
+        hands
+            .into_iter()
+            .max_by_key(|hand| hand.key)
+            .ok_or_else(|| AocError::WrongHandSize {
+                hand: cards.iter().map(|card| card.rank).collect(),
+                expected: 5,
+            })
+    }
+}
+
+fn check_unique(cards: &[PokerCard]) -> Result<(), AocError> {
+    let mut seen = HashSet::new();
+    match cards.iter().find(|card| !seen.insert(**card)) {
+        Some(card) => Err(AocError::DuplicatePokerCard(format!(
+            "{}{:?}",
+            card.rank, card.suit
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Texas Hold'em showdown: every player's best hand out of the board and their hole
+/// cards, with the indices of the winning players (several when the pot is split)
+pub fn showdown(
+    board: &[PokerCard],
+    hole_cards: &[Vec<PokerCard>],
+) -> Result<(Vec<PokerHand>, Vec<usize>), AocError> {
+    let all_cards: Vec<PokerCard> = board
+        .iter()
+        .chain(hole_cards.iter().flatten())
+        .copied()
+        .collect();
+    check_unique(&all_cards)?;
+
+    let hands = hole_cards
+        .iter()
+        .map(|hole| {
+            let cards: Vec<PokerCard> = board.iter().chain(hole.iter()).copied().collect();
+            PokerHand::best_of(&cards)
+        })
+        .collect::<Result<Vec<PokerHand>, AocError>>()?;
+
+    let best = hands.iter().map(|hand| hand.key).max();
+    let winners = hands
+        .iter()
+        .enumerate()
+        .filter(|(_, hand)| Some(hand.key) == best)
+        .map(|(i, _)| i)
+        .collect();
+
+    Ok((hands, winners))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categories() -> miette::Result<()> {
+        let cases = [
+            ("2H 3D 5S 9C KD", PokerCategory::HighCard),
+            ("2H 2D 5S 9C KD", PokerCategory::OnePair),
+            ("2H 2D 5S 5C KD", PokerCategory::TwoPair),
+            ("2H 2D 2S 9C KD", PokerCategory::ThreeOfKind),
+            ("AH 2D 3S 4C 5D", PokerCategory::Straight),
+            ("2H 7H 5H 9H KH", PokerCategory::Flush),
+            ("2H 2D 2S KC KD", PokerCategory::FullHouse),
+            ("2H 2D 2S 2C KD", PokerCategory::FourOfKind),
+            ("TS JS QS KS AS", PokerCategory::StraightFlush),
+        ];
+        for (hand, category) in cases {
+            assert_eq!(category, PokerHand::parse(hand)?.category, "{hand}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tie_breaking() -> miette::Result<()> {
+        let key = |hand: &str| PokerHand::parse(hand).map(|hand| hand.key);
+
+        // pairs first, then kickers
+        assert!(key("3H 3D AS KC QD")? < key("4H 4D 2S 5C 6D")?);
+        assert!(key("4H 4D 2S 5C 7D")? > key("4S 4C 2D 5H 6H")?);
+        // full houses compare the triple first
+        assert!(key("3H 3D 3S 2C 2D")? > key("2H 2S 2C AH AD")?);
+        // the wheel is the lowest straight
+        assert!(key("AH 2D 3S 4C 5D")? < key("2H 3C 4S 5C 6D")?);
+        assert_eq!(key("2H 3C 4S 5C 6D")?, key("2D 3S 4D 5S 6C")?);
+
+        assert!(matches!(
+            PokerHand::parse("2H 2H 5S 9C KD"),
+            Err(AocError::DuplicatePokerCard(_))
+        ));
+        assert!(matches!(
+            PokerHand::parse("2H 3D 5S 9C 1D"),
+            Err(AocError::InvalidPokerCard(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_showdown() -> miette::Result<()> {
+        let board = parse_cards("2H 7D 8S 9C KH")?;
+        let players = vec![
+            parse_cards("TD JC")?,
+            parse_cards("KS KD")?,
+            parse_cards("6H TH")?,
+        ];
+        let (hands, winners) = showdown(&board, &players)?;
+
+        assert_eq!(PokerCategory::Straight, hands[0].category);
+        assert_eq!(PokerCategory::ThreeOfKind, hands[1].category);
+        assert_eq!(PokerCategory::Straight, hands[2].category);
+        assert_eq!(vec![0], winners);
+
+        let (_, winners) = showdown(
+            &parse_cards("AH AD AS AC KH")?,
+            &[parse_cards("2C 3C")?, parse_cards("4D 5D")?],
+        )?;
+        assert_eq!(vec![0, 1], winners);
+        Ok(())
+    }
+}
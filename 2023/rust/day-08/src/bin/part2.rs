@@ -1,3 +1,5 @@
+use day_08::graph::{condensed_to_dot, to_dot};
+use day_08::map::Map;
 use day_08::part2::process;
 use miette::Context;
 
@@ -14,6 +16,17 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input2.txt");
+
+    if std::env::args().any(|arg| arg == "--dot") {
+        let map: Map = file.parse()?;
+        if std::env::args().any(|arg| arg == "--condensation") {
+            print!("{}", condensed_to_dot(&map));
+        } else {
+            print!("{}", to_dot(&map));
+        }
+        return Ok(());
+    }
+
     let result = process(file).context("process part 2")?;
     println!("{}", result);
     Ok(())
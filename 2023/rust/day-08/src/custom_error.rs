@@ -7,4 +7,32 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("the ghosts never all stand on a Z node at the same time")]
+    #[diagnostic(code(aoc::ghosts_never_meet))]
+    GhostsNeverMeet,
+
+    #[error("invalid map data `{0}`")]
+    #[diagnostic(code(aoc::invalid_map))]
+    InvalidMap(String),
+
+    #[error("invalid direction {0}, expected L or R")]
+    #[diagnostic(code(aoc::invalid_direction))]
+    InvalidDirection(char),
+
+    #[error("node {0} is defined more than once")]
+    #[diagnostic(code(aoc::duplicate_node))]
+    DuplicateNode(String),
+
+    #[error("node {node} points to {target}, which doesn't exist")]
+    #[diagnostic(code(aoc::dangling_node))]
+    DanglingNode { node: String, target: String },
+
+    #[error("the map has no node {0}")]
+    #[diagnostic(code(aoc::missing_node))]
+    MissingNode(String),
+
+    #[error("following the instructions from {start} never reaches {target}")]
+    #[diagnostic(code(aoc::unreachable_target))]
+    UnreachableTarget { start: String, target: String },
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+
+use num_integer::Integer;
+
+/// Every step at which a ghost stands on a target node: the `transient` hits happen
+/// once before the walk settles into its cycle, each `periodic` hit repeats every
+/// `period` steps forever after
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hits {
+    pub transient: BTreeSet<u128>,
+    pub periodic: BTreeSet<u128>,
+    pub period: u128,
+}
+
+impl Hits {
+    pub fn contains(&self, step: u128) -> bool {
+        self.transient.contains(&step)
+            || self
+                .periodic
+                .iter()
+                .any(|&first| step >= first && (step - first).is_multiple_of(self.period))
+    }
+
+    pub fn first(&self) -> Option<u128> {
+        self.transient
+            .first()
+            .into_iter()
+            .chain(self.periodic.first())
+            .min()
+            .copied()
+    }
+
+    /// Steps at which both walks are on a target node
+    pub fn intersect(&self, other: &Hits) -> Hits {
+        let transient = self
+            .transient
+            .iter()
+            .filter(|step| other.contains(**step))
+            .chain(other.transient.iter().filter(|step| self.contains(**step)))
+            .copied()
+            .collect();
+
+        let periodic = self
+            .periodic
+            .iter()
+            .flat_map(|a| {
+                other
+                    .periodic
+                    .iter()
+                    .filter_map(|b| crt(*a, self.period, *b, other.period))
+            })
+            .collect();
+
+        Hits {
+            transient,
+            periodic,
+            period: self.period.lcm(&other.period),
+        }
+    }
+}
+
+/// Smallest `x >= max(a, b)` with `x ≡ a (mod m)` and `x ≡ b (mod n)`
+fn crt(a: u128, m: u128, b: u128, n: u128) -> Option<u128> {
+    let (m_, n_) = (m as i128, n as i128);
+    let gcd = m_.extended_gcd(&n_);
+    let diff = b as i128 - a as i128;
+    if diff % gcd.gcd != 0 {
+        return None;
+    }
+
+    let modulus = n_ / gcd.gcd;
+    let k = (diff / gcd.gcd % modulus * (gcd.x % modulus)).rem_euclid(modulus);
+    let lcm = m * (modulus as u128);
+    let x = a + m * k as u128;
+
+    let floor = a.max(b);
+    Some(if x >= floor {
+        x
+    } else {
+        x + (floor - x).div_ceil(lcm) * lcm
+    })
+}
+
+/// Follows a walk until its `(node, instruction index)` state repeats, recording
+/// every step after the first at which it stands on a target node
+pub fn cycle_hits<N: Copy + Eq + Hash>(
+    instructions: usize,
+    start: N,
+    mut step: impl FnMut(N, usize) -> N,
+    is_target: impl Fn(N) -> bool,
+) -> Hits {
+    let mut seen: HashMap<(N, usize), u128> = HashMap::new();
+    let mut targets = Vec::new();
+    let mut node = start;
+    let mut steps: u128 = 0;
+
+    let cycle_start = loop {
+        let index = (steps % instructions as u128) as usize;
+        if let Some(first) = seen.insert((node, index), steps) {
+            break first;
+        }
+        if is_target(node) {
+            targets.push(steps);
+        }
+        node = step(node, index);
+        steps += 1;
+    };
+    let period = steps - cycle_start;
+
+    let (transient, periodic): (Vec<u128>, Vec<u128>) =
+        targets.into_iter().partition(|hit| *hit < cycle_start);
+
+    Hits {
+        transient: transient.into_iter().filter(|hit| *hit > 0).collect(),
+        // standing on a target before moving doesn't count, but the next lap does
+        periodic: periodic
+            .into_iter()
+            .map(|hit| if hit == 0 { period } else { hit })
+            .collect(),
+        period,
+    }
+}
+
+/// First step at which every walk is on a target node at the same time
+pub fn first_common(hits: &[Hits]) -> Option<u128> {
+    let (first, rest) = hits.split_first()?;
+    rest.iter()
+        .fold(first.clone(), |common, hits| common.intersect(hits))
+        .first()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some(4), crt(0, 2, 1, 3));
+        assert_eq!(Some(10), crt(4, 6, 2, 4));
+        assert_eq!(None, crt(0, 2, 1, 4));
+        assert_eq!(Some(12), crt(12, 3, 2, 5));
+    }
+
+    #[test]
+    fn test_first_common() {
+        // 0 -> 1 -> 2 -> 1 ..., target 2: steps 2, 4, 6, ...
+        let evens = cycle_hits(1, 0, |n, _| if n == 2 { 1 } else { n + 1 }, |n| n == 2);
+        // 0 -> 1 -> 2 -> 3 -> 1 ..., target 1: steps 1, 4, 7, ...
+        let threes = cycle_hits(1, 0, |n, _| if n == 3 { 1 } else { n + 1 }, |n| n == 1);
+        // 0 -> 1 -> 2 -> 1 ..., target 1: steps 1, 3, 5, ...
+        let odds = cycle_hits(1, 0, |n, _| if n == 2 { 1 } else { n + 1 }, |n| n == 1);
+
+        assert_eq!(Some(4), first_common(&[evens.clone(), threes.clone()]));
+        assert_eq!(Some(1), first_common(&[threes, odds.clone()]));
+        assert_eq!(None, first_common(&[evens, odds]));
+    }
+}
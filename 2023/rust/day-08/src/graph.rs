@@ -0,0 +1,130 @@
+use std::fmt;
+
+use petgraph::algo::condensation;
+use petgraph::dot::Dot;
+use petgraph::graph::{DiGraph, NodeIndex};
+
+use crate::map::{Direction, Map};
+
+/// Directed graph of the network with one edge per direction, so node
+/// `NodeIndex::new(id)` is the map's node `id`
+pub fn to_digraph(map: &Map) -> DiGraph<String, Direction> {
+    let mut graph = DiGraph::with_capacity(map.len(), map.len() * 2);
+    for node in map.nodes() {
+        graph.add_node(map.name(node).to_owned());
+    }
+    for node in map.nodes() {
+        for direction in [Direction::Left, Direction::Right] {
+            graph.add_edge(
+                NodeIndex::new(node),
+                NodeIndex::new(map.child(node, direction)),
+                direction,
+            );
+        }
+    }
+    graph
+}
+
+/// Fill for a node or component, given whether it holds a start and an end node
+fn highlight(start: bool, end: bool) -> String {
+    match (start, end) {
+        (true, true) => "style = wedged, fillcolor = \"palegreen:salmon\"".into(),
+        (true, false) => "style = filled, fillcolor = palegreen".into(),
+        (false, true) => "style = filled, fillcolor = salmon".into(),
+        (false, false) => String::new(),
+    }
+}
+
+/// Graphviz DOT text with `L`/`R` edge labels, start nodes in green and end nodes in red
+pub fn to_dot(map: &Map) -> String {
+    let graph = to_digraph(map);
+    Dot::with_attr_getters(&graph, &[], &|_, _| String::new(), &|_, (_, name)| {
+        highlight(name.ends_with('A'), name.ends_with('Z'))
+    })
+    .to_string()
+}
+
+/// The network with every strongly connected component collapsed into one node
+pub fn condensed(map: &Map) -> DiGraph<Vec<String>, Direction> {
+    condensation(to_digraph(map), true)
+}
+
+/// Node weight of the condensed DOT output, keeping the component's names intact
+struct Component<'a>(&'a [String]);
+
+impl fmt::Display for Component<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} nodes: {}", self.0.len(), self.0.join(", "))
+    }
+}
+
+impl Component<'_> {
+    fn highlight(&self) -> String {
+        highlight(
+            self.0.iter().any(|name| name.ends_with('A')),
+            self.0.iter().any(|name| name.ends_with('Z')),
+        )
+    }
+}
+
+/// DOT text of the condensation, each component labelled with its size and nodes.
+/// A component holding both start and end nodes is drawn in both colours.
+pub fn condensed_to_dot(map: &Map) -> String {
+    let condensed = condensed(map);
+    let graph = condensed.map(|_, names| Component(names), |_, direction| *direction);
+    Dot::with_attr_getters(&graph, &[], &|_, _| String::new(), &|_, (_, component)| {
+        component.highlight()
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn test_export() -> miette::Result<()> {
+        let map: Map = INPUT.parse()?;
+        let graph = to_digraph(&map);
+        assert_eq!(8, graph.node_count());
+        assert_eq!(16, graph.edge_count());
+
+        let dot = to_dot(&map);
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("label = \"11A\" style = filled, fillcolor = palegreen"));
+        assert!(dot.contains("0 -> 1 [ label = \"L\" ]"));
+        assert!(dot.contains("0 -> 7 [ label = \"R\" ]"));
+
+        let mut sizes: Vec<usize> = condensed(&map)
+            .node_weights()
+            .map(|names| names.len())
+            .collect();
+        sizes.sort();
+        assert_eq!(vec![1, 1, 1, 2, 3], sizes);
+
+        let dot = condensed_to_dot(&map);
+        assert!(dot.contains("label = \"1 nodes: 11A\" style = filled, fillcolor = palegreen"));
+        assert!(dot.contains("label = \"1 nodes: XXX\" ]"));
+        assert!(!dot.contains("wedged"));
+
+        // names containing the label separators, and a component with a start and an end
+        let mixed: Map =
+            "L\n\nX: A = (B: Z, X: A)\nB: Z = (Y, A, B)\nA, B = (X: A, X: A)\nY = (Y, Y)"
+                .parse()?;
+        let dot = condensed_to_dot(&mixed);
+        assert!(dot.contains("label = \"1 nodes: Y\" ]"));
+        assert!(dot.contains("style = wedged, fillcolor = \"palegreen:salmon\""));
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
 pub mod custom_error;
+pub mod ghost;
+pub mod graph;
+pub mod map;
 
 pub mod part1;
 pub mod part2;
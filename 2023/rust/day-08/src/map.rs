@@ -0,0 +1,236 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::custom_error::AocError;
+
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+/// Desert network with node names interned to ids, children stored as id arrays
+#[derive(Debug)]
+pub struct Map {
+    directions: Vec<Direction>,
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    left: Vec<NodeId>,
+    right: Vec<NodeId>,
+}
+
+impl FromStr for Map {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.replace("\r\n", "\n");
+        let (directions_part, nodes_part) = s
+            .split_once("\n\n")
+            .ok_or_else(|| AocError::InvalidMap(s.lines().next().unwrap_or_default().into()))?;
+
+        let directions = directions_part
+            .trim()
+            .chars()
+            .map(|c| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(AocError::InvalidDirection(c)),
+            })
+            .collect::<Result<Vec<Direction>, AocError>>()?;
+        if directions.is_empty() {
+            return Err(AocError::InvalidMap(directions_part.into()));
+        }
+
+        let mut names = Vec::new();
+        let mut ids = HashMap::new();
+        let mut children = Vec::new();
+
+        for line in nodes_part.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let invalid = || AocError::InvalidMap(line.into());
+            let (name, node_data) = line.split_once('=').ok_or_else(invalid)?;
+            let (left, right) = node_data
+                .trim()
+                .strip_prefix('(')
+                .and_then(|data| data.strip_suffix(')'))
+                .and_then(|data| data.split_once(','))
+                .ok_or_else(invalid)?;
+
+            let name = name.trim().to_owned();
+            if ids.insert(name.clone(), names.len()).is_some() {
+                return Err(AocError::DuplicateNode(name));
+            }
+            names.push(name);
+            children.push((left.trim(), right.trim()));
+        }
+
+        let resolve = |node: usize, target: &str| {
+            ids.get(target)
+                .copied()
+                .ok_or_else(|| AocError::DanglingNode {
+                    node: names[node].clone(),
+                    target: target.into(),
+                })
+        };
+        let (left, right) = children
+            .iter()
+            .enumerate()
+            .map(|(node, (left, right))| Ok((resolve(node, left)?, resolve(node, right)?)))
+            .collect::<Result<(Vec<NodeId>, Vec<NodeId>), AocError>>()?;
+
+        Ok(Self {
+            directions,
+            names,
+            ids,
+            left,
+            right,
+        })
+    }
+}
+
+impl Map {
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len()
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, node: NodeId) -> &str {
+        &self.names[node]
+    }
+
+    pub fn child(&self, node: NodeId, direction: Direction) -> NodeId {
+        match direction {
+            Direction::Left => self.left[node],
+            Direction::Right => self.right[node],
+        }
+    }
+
+    /// Node reached from `node` by following the instruction at `index`
+    pub fn step(&self, node: NodeId, index: usize) -> NodeId {
+        self.child(node, self.directions[index % self.directions.len()])
+    }
+
+    /// Whether any target can be reached from `start` along the directions the
+    /// instructions actually use
+    pub fn can_reach(&self, start: NodeId, is_target: impl Fn(NodeId) -> bool) -> bool {
+        let used: Vec<Direction> = [Direction::Left, Direction::Right]
+            .into_iter()
+            .filter(|d| self.directions.contains(d))
+            .collect();
+
+        let mut seen = vec![false; self.len()];
+        let mut queue = VecDeque::from([start]);
+        seen[start] = true;
+        while let Some(node) = queue.pop_front() {
+            for next in used.iter().map(|d| self.child(node, *d)) {
+                if is_target(next) {
+                    return true;
+                }
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Steps taken from `start` until the walk first lands on a target, failing
+    /// instead of looping forever when it never does
+    pub fn steps_until(
+        &self,
+        start: NodeId,
+        is_target: impl Fn(NodeId) -> bool,
+        target: &str,
+    ) -> Result<u64, AocError> {
+        let unreachable = || AocError::UnreachableTarget {
+            start: self.name(start).into(),
+            target: target.into(),
+        };
+        if !self.can_reach(start, &is_target) {
+            return Err(unreachable());
+        }
+
+        // every (node, instruction) state has been visited by then, so the walk is cycling
+        let limit = self.len() * self.directions.len();
+        let mut node = start;
+        for steps in 0..limit {
+            node = self.step(node, steps);
+            if is_target(node) {
+                return Ok(steps as u64 + 1);
+            }
+        }
+        Err(unreachable())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            "LR\n\nAAA = (BBB, AAA)".parse::<Map>(),
+            Err(AocError::DanglingNode { .. })
+        ));
+        assert!(matches!(
+            "LX\n\nAAA = (AAA, AAA)".parse::<Map>(),
+            Err(AocError::InvalidDirection('X'))
+        ));
+        assert!(matches!(
+            "LR\n\nAAA = AAA, AAA".parse::<Map>(),
+            Err(AocError::InvalidMap(_))
+        ));
+        assert!(matches!(
+            "LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)".parse::<Map>(),
+            Err(AocError::DuplicateNode(_))
+        ));
+    }
+
+    #[test]
+    fn test_unreachable() -> miette::Result<()> {
+        // ZZZ hangs off the right branch, but the instructions only go left
+        let map: Map = "L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)".parse()?;
+        let zzz = map.id("ZZZ").unwrap();
+        assert!(matches!(
+            map.steps_until(map.id("AAA").unwrap(), |n| n == zzz, "ZZZ"),
+            Err(AocError::UnreachableTarget { .. })
+        ));
+
+        // reachable, but the walk alternates between AAA and BBB in step with the instructions
+        let map: Map = "LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)".parse()?;
+        let zzz = map.id("ZZZ").unwrap();
+        assert!(matches!(
+            map.steps_until(map.id("AAA").unwrap(), |n| n == zzz, "ZZZ"),
+            Err(AocError::UnreachableTarget { .. })
+        ));
+        Ok(())
+    }
+}
@@ -1,82 +1,17 @@
-use std::{collections::BTreeMap, str::FromStr, string::ParseError};
-
 use crate::custom_error::AocError;
-
-#[derive(Debug)]
-struct Node {
-    left: String,
-    right: String,
-}
-
-#[derive(Debug)]
-struct Map {
-    directions: Vec<char>,
-    nodes: BTreeMap<String, Node>,
-}
-
-impl FromStr for Map {
-    type Err = ParseError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (directions_part, nodes_part) = s.split_once("\n\n").expect("Invalid Map data");
-
-        let mut nodes = BTreeMap::new();
-
-        nodes_part.lines().for_each(|line| {
-            let (index, node_data) = line.split_once('=').unwrap();
-            let index = index.trim().to_owned();
-            let node_data = node_data.replace(['(', ')'], "");
-            let (left, right) = node_data.split_once(',').unwrap();
-            nodes.insert(
-                index,
-                Node {
-                    left: left.trim().to_owned(),
-                    right: right.trim().to_owned(),
-                },
-            );
-        });
-
-        Ok(Self {
-            directions: directions_part.chars().collect(),
-            nodes,
-        })
-    }
-}
-
-impl Map {
-    fn move_until_end(&self) -> u32 {
-        let mut steps = 0;
-        let total_directions = self.directions.len();
-        let mut current_position = self.nodes.keys().nth(0).unwrap().as_str();
-
-        let mut i = 0;
-        while i < total_directions {
-            let node = self.nodes.get(current_position).unwrap();
-            current_position = if self.directions[i] == 'R' {
-                &node.right
-            } else {
-                &node.left
-            }
-            .as_str();
-
-            steps += 1;
-            if current_position.contains("ZZZ") {
-                break;
-            }
-
-            i += 1;
-            if i == total_directions {
-                i = 0;
-            }
-        }
-
-        steps
-    }
-}
+use crate::map::Map;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let map_data = Map::from_str(input).unwrap();
-    let steps = map_data.move_until_end();
+    let map: Map = input.parse()?;
+    let start = map
+        .id("AAA")
+        .ok_or_else(|| AocError::MissingNode("AAA".into()))?;
+    let end = map
+        .id("ZZZ")
+        .ok_or_else(|| AocError::MissingNode("ZZZ".into()))?;
+
+    let steps = map.steps_until(start, |node| node == end, "ZZZ")?;
     Ok(steps.to_string())
 }
 
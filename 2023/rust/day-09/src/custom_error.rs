@@ -7,4 +7,16 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid number `{0}`")]
+    #[diagnostic(code(aoc::invalid_number))]
+    InvalidNumber(String),
+
+    #[error("the differences of `{0}` never reach zero")]
+    #[diagnostic(code(aoc::no_zero_differences))]
+    NoZeroDifferences(String),
+
+    #[error("arithmetic overflow while extrapolating")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
\ No newline at end of file
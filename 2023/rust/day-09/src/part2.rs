@@ -1,42 +1,9 @@
-use std::fmt::Display;
-
 use crate::custom_error::AocError;
-
-fn find_next_number(input: Vec<i64>) -> i64 {
-    let mut output = Vec::new();
-    get_diff_vec(&input, &mut output);
-    output.reverse();
-    output.into_iter().fold(0, |acc, v| v - acc)
-}
-
-fn get_diff_vec(input: &Vec<i64>, output_vec: &mut Vec<i64>) {
-    let next_input: Vec<i64> = input.windows(2).map(|w| w[1] - w[0]).collect();
-    if next_input.iter().all(|&x| x == 0) {
-        output_vec.push(*input.first().unwrap());
-        return;
-    }
-
-    output_vec.push(*input.first().unwrap());
-    get_diff_vec(&next_input, output_vec)
-}
-
-fn run(input: &str) -> impl Display {
-    let result: i64 = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|d| d.parse::<i64>().unwrap())
-                .collect::<Vec<i64>>()
-        })
-        .map(find_next_number)
-        .sum();
-
-    result
-}
+use crate::polynomial::sum_extrapolated;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    Ok(run(input).to_string())
+    Ok(sum_extrapolated(input, -1)?.to_string())
 }
 
 #[cfg(test)]
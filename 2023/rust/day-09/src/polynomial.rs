@@ -0,0 +1,283 @@
+use std::fmt;
+
+use crate::custom_error::AocError;
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Exact fraction kept in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Some(Rational {
+            numerator: numerator.checked_div(divisor)?,
+            denominator: denominator.checked_div(divisor)?,
+        })
+    }
+
+    pub fn integer(value: i128) -> Self {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        let divisor = gcd(self.denominator, other.denominator);
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator / divisor)?
+            .checked_add(other.numerator.checked_mul(self.denominator / divisor)?)?;
+        Rational::new(
+            numerator,
+            self.denominator.checked_mul(other.denominator / divisor)?,
+        )
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // cross-reduce first so the products stay as small as possible
+        let a = gcd(self.numerator, other.denominator).max(1);
+        let b = gcd(other.numerator, self.denominator).max(1);
+        Rational::new(
+            (self.numerator / a).checked_mul(other.numerator / b)?,
+            (self.denominator / b).checked_mul(other.denominator / a)?,
+        )
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// A sequence fitted to its Newton forward-difference polynomial, with `x = 0`
+/// at the first value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewtonPolynomial {
+    /// First entry of every difference row down to the last non-zero one
+    differences: Vec<i128>,
+    len: usize,
+}
+
+impl NewtonPolynomial {
+    pub fn fit(values: &[i128]) -> Result<Self, AocError> {
+        let mut differences = Vec::new();
+        let mut row = values.to_vec();
+
+        loop {
+            let Some(first) = row.first() else {
+                return Err(AocError::NoZeroDifferences(format_sequence(values)));
+            };
+            if row.iter().all(|value| *value == 0) {
+                break;
+            }
+            differences.push(*first);
+            row = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]).ok_or(AocError::Overflow))
+                .collect::<Result<Vec<i128>, AocError>>()?;
+        }
+
+        Ok(NewtonPolynomial {
+            differences,
+            len: values.len(),
+        })
+    }
+
+    /// Degree of the polynomial, taking the zero polynomial as degree 0
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    pub fn leading_differences(&self) -> &[i128] {
+        &self.differences
+    }
+
+    /// Coefficients of `x^0`, `x^1`, ... up to the degree
+    pub fn coefficients(&self) -> Result<Vec<Rational>, AocError> {
+        let mut coefficients = vec![Rational::integer(0); self.degree() + 1];
+        // x (x - 1) ... (x - k + 1), lowest power first
+        let mut falling = vec![1_i128];
+        let mut factorial = 1_i128;
+
+        for (k, difference) in self.differences.iter().enumerate() {
+            if k > 0 {
+                let shift = k as i128 - 1;
+                let mut next = vec![0; falling.len() + 1];
+                for (power, coefficient) in falling.iter().enumerate() {
+                    next[power + 1] = coefficient
+                        .checked_add(next[power + 1])
+                        .ok_or(AocError::Overflow)?;
+                    next[power] = coefficient
+                        .checked_mul(shift)
+                        .and_then(|shifted| next[power].checked_sub(shifted))
+                        .ok_or(AocError::Overflow)?;
+                }
+                falling = next;
+                factorial = factorial.checked_mul(k as i128).ok_or(AocError::Overflow)?;
+            }
+
+            let scale = Rational::new(*difference, factorial).ok_or(AocError::Overflow)?;
+            for (power, coefficient) in falling.iter().enumerate() {
+                coefficients[power] = Rational::integer(*coefficient)
+                    .checked_mul(scale)
+                    .and_then(|term| coefficients[power].checked_add(term))
+                    .ok_or(AocError::Overflow)?;
+            }
+        }
+
+        Ok(coefficients)
+    }
+
+    pub fn value_at(&self, x: i128) -> Result<i128, AocError> {
+        let mut value = 0_i128;
+        // binomial coefficient (x choose k), which stays an integer for negative x too
+        let mut binomial = 1_i128;
+
+        for (k, difference) in self.differences.iter().enumerate() {
+            if k > 0 {
+                binomial = x
+                    .checked_sub(k as i128 - 1)
+                    .and_then(|factor| binomial.checked_mul(factor))
+                    .ok_or(AocError::Overflow)?
+                    / k as i128;
+            }
+            value = difference
+                .checked_mul(binomial)
+                .and_then(|term| value.checked_add(term))
+                .ok_or(AocError::Overflow)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Value `steps` past the last one, or before the first one when `steps` is negative
+    pub fn extrapolate(&self, steps: i128) -> Result<i128, AocError> {
+        if steps < 0 {
+            self.value_at(steps)
+        } else {
+            let x = (self.len as i128 - 1)
+                .checked_add(steps)
+                .ok_or(AocError::Overflow)?;
+            self.value_at(x)
+        }
+    }
+}
+
+fn format_sequence(values: &[i128]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub fn parse_sequences(input: &str) -> Result<Vec<Vec<i128>>, AocError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.split_whitespace()
+                .map(|d| {
+                    d.parse::<i128>()
+                        .map_err(|_| AocError::InvalidNumber(d.to_string()))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Sum of every sequence extrapolated `steps` in either direction
+pub fn sum_extrapolated(input: &str, steps: i128) -> Result<i128, AocError> {
+    parse_sequences(input)?
+        .iter()
+        .map(|values| NewtonPolynomial::fit(values)?.extrapolate(steps))
+        .try_fold(0_i128, |sum, value| {
+            sum.checked_add(value?).ok_or(AocError::Overflow)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit() -> miette::Result<()> {
+        let polynomial = NewtonPolynomial::fit(&[1, 3, 6, 10, 15, 21])?;
+        assert_eq!(2, polynomial.degree());
+        assert_eq!(&[1, 2, 1], polynomial.leading_differences());
+        // (x + 1)(x + 2) / 2
+        assert_eq!(
+            vec![
+                Rational::integer(1),
+                Rational::new(3, 2).unwrap(),
+                Rational::new(1, 2).unwrap()
+            ],
+            polynomial.coefficients()?
+        );
+        assert_eq!(28, polynomial.extrapolate(1)?);
+        assert_eq!(36, polynomial.extrapolate(2)?);
+        assert_eq!(0, polynomial.extrapolate(-1)?);
+        assert_eq!(0, polynomial.extrapolate(-2)?);
+        assert_eq!(1, polynomial.extrapolate(-3)?);
+
+        let polynomial = NewtonPolynomial::fit(&[10, 13, 16, 21, 30, 45])?;
+        assert_eq!(3, polynomial.degree());
+        assert_eq!(68, polynomial.extrapolate(1)?);
+        assert_eq!(5, polynomial.extrapolate(-1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            NewtonPolynomial::fit(&[1, 2, 4, 8]),
+            Err(AocError::NoZeroDifferences(_))
+        ));
+        assert!(matches!(
+            NewtonPolynomial::fit(&[i128::MIN, i128::MAX]),
+            Err(AocError::Overflow)
+        ));
+        assert!(matches!(
+            NewtonPolynomial::fit(&[0, i128::MAX / 2, i128::MAX - 1])
+                .and_then(|polynomial| polynomial.extrapolate(2)),
+            Err(AocError::Overflow)
+        ));
+        let polynomial = NewtonPolynomial::fit(&[0, 1, 3, 6]).unwrap();
+        assert!(matches!(
+            polynomial.value_at(i128::MIN),
+            Err(AocError::Overflow)
+        ));
+        assert!(matches!(
+            polynomial.extrapolate(i128::MAX),
+            Err(AocError::Overflow)
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::custom_error::AocError;
+use crate::pipes::{clean_map, parse, trace_loop, Coord, Direction, Tile};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AreaMethod {
+    /// Scan each row of the cleaned map, flipping inside/outside on north-facing pipes
+    ScanParity,
+    /// Shoelace area of the ordered loop, turned into a tile count with Pick's theorem
+    Shoelace,
+    /// Flood the outside of a 3x upscaled map, which leaves room to squeeze between pipes
+    FloodFill,
+}
+
+/// Which tiles of the cleaned map lie inside the loop, by row scan parity
+pub fn inside_tiles(map: &[Vec<Tile>]) -> Vec<Vec<bool>> {
+    // scan from top to bottom and left to right, counting how many tiles are inside the loop.
+    // keep track of a boolean that tells me if I'm inside the loop
+    // every time I cross a vertical pipe that does not horizontally block the top (the place where I am in the loop), flip that state
+    let mut inside = false;
+
+    map.iter()
+        .map(|row| {
+            row.iter()
+                .map(|tile| match tile {
+                    Tile::Empty => inside,
+                    Tile::NorthSouth | Tile::NorthWest | Tile::NorthEast => {
+                        inside = !inside;
+                        false
+                    }
+                    _ => false,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn scan_parity(map: &[Vec<Tile>]) -> usize {
+    inside_tiles(map)
+        .iter()
+        .flatten()
+        .filter(|inside| **inside)
+        .count()
+}
+
+pub fn shoelace(loop_coords: &[Coord]) -> usize {
+    let twice_area: i64 = loop_coords
+        .iter()
+        .zip(loop_coords.iter().cycle().skip(1))
+        .map(|(a, b)| a.col_idx as i64 * b.row_idx as i64 - b.col_idx as i64 * a.row_idx as i64)
+        .sum();
+
+    // Pick's theorem: A = I + B/2 - 1, with every loop tile a boundary point
+    (twice_area.unsigned_abs() as usize + 2).saturating_sub(loop_coords.len()) / 2
+}
+
+pub fn flood_fill(map: &[Vec<Tile>]) -> usize {
+    let height = map.len() * 3;
+    let width = map.first().map_or(0, |row| row.len()) * 3;
+
+    let mut blocked = vec![vec![false; width]; height];
+    for (row_idx, row) in map.iter().enumerate() {
+        for (col_idx, tile) in row.iter().enumerate() {
+            let Some(ends) = tile.ends() else {
+                continue;
+            };
+            let (centre_row, centre_col) = (row_idx * 3 + 1, col_idx * 3 + 1);
+            blocked[centre_row][centre_col] = true;
+            for end in ends {
+                let (r, c) = match end {
+                    Direction::North => (centre_row - 1, centre_col),
+                    Direction::South => (centre_row + 1, centre_col),
+                    Direction::West => (centre_row, centre_col - 1),
+                    Direction::East => (centre_row, centre_col + 1),
+                };
+                blocked[r][c] = true;
+            }
+        }
+    }
+
+    let mut outside = vec![vec![false; width]; height];
+    let mut queue: VecDeque<(usize, usize)> = (0..height)
+        .flat_map(|r| [(r, 0), (r, width - 1)])
+        .chain((0..width).flat_map(|c| [(0, c), (height - 1, c)]))
+        .filter(|(r, c)| !blocked[*r][*c])
+        .collect();
+    for (r, c) in queue.iter() {
+        outside[*r][*c] = true;
+    }
+
+    while let Some((r, c)) = queue.pop_front() {
+        let neighbours = [
+            (r.wrapping_sub(1), c),
+            (r + 1, c),
+            (r, c.wrapping_sub(1)),
+            (r, c + 1),
+        ];
+        for (nr, nc) in neighbours {
+            if nr < height && nc < width && !blocked[nr][nc] && !outside[nr][nc] {
+                outside[nr][nc] = true;
+                queue.push_back((nr, nc));
+            }
+        }
+    }
+
+    map.iter()
+        .enumerate()
+        .flat_map(|(row_idx, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(col_idx, tile)| (row_idx, col_idx, tile))
+        })
+        .filter(|(row_idx, col_idx, tile)| {
+            **tile == Tile::Empty && !outside[row_idx * 3 + 1][col_idx * 3 + 1]
+        })
+        .count()
+}
+
+/// Number of tiles enclosed by the main loop
+pub fn enclosed_tiles(input: &str, method: AreaMethod) -> Result<usize, AocError> {
+    let (map, start) = parse(input)?;
+    let (start_pipe, loop_coords) = trace_loop(start, &map)?;
+
+    let loop_set: HashSet<Coord> = loop_coords.iter().copied().collect();
+    Ok(match method {
+        AreaMethod::ScanParity => scan_parity(&clean_map(start_pipe, &loop_set, map)),
+        AreaMethod::Shoelace => shoelace(&loop_coords),
+        AreaMethod::FloodFill => flood_fill(&clean_map(start_pipe, &loop_set, map)),
+    })
+}
@@ -1,4 +1,5 @@
 use day_10::part2::process;
+use day_10::render::{render, Overlay};
 use miette::Context;
 
 #[cfg(feature = "dhat-heap")]
@@ -14,6 +15,17 @@ fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
     let file = include_str!("../../input2.txt");
+
+    if std::env::args().any(|arg| arg == "--render") {
+        let overlay = if std::env::args().any(|arg| arg == "--distances") {
+            Overlay::Distance
+        } else {
+            Overlay::Pipes
+        };
+        print!("{}", render(file, overlay)?);
+        return Ok(());
+    }
+
     let result = process(file).context("process part 2")?;
     println!("{}", result);
     Ok(())
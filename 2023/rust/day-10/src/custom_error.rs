@@ -7,4 +7,35 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid tile `{0}`")]
+    #[diagnostic(code(aoc::invalid_tile))]
+    InvalidTile(char),
+
+    #[error("row {row} has {found} tiles, expected {expected}")]
+    #[diagnostic(code(aoc::ragged_map))]
+    RaggedMap {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("the map has no start tile")]
+    #[diagnostic(code(aoc::missing_start))]
+    MissingStart,
+
+    #[error("no pipe loop leads back to the start at row {row}, column {col}")]
+    #[diagnostic(code(aoc::no_loop))]
+    NoLoop { row: usize, col: usize },
+
+    #[error("the start tile at row {row}, column {col} could be any of {shapes}")]
+    #[diagnostic(
+        code(aoc::ambiguous_start),
+        help("more than one pipe shape closes a loop back to the start")
+    )]
+    AmbiguousStart {
+        row: usize,
+        col: usize,
+        shapes: String,
+    },
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
+pub mod area;
 pub mod custom_error;
+pub mod pipes;
+pub mod render;
 
 pub mod part1;
 pub mod part2;
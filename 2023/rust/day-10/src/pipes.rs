@@ -0,0 +1,300 @@
+use std::collections::HashSet;
+
+use crate::custom_error::AocError;
+
+//Ref: https://nickymeuleman.netlify.app/garden/aoc2023-day10
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::East => Direction::West,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    // S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+    Start,
+    // . is ground; there is no pipe in this tile.
+    Empty,
+    // | is a vertical pipe connecting north and south.
+    NorthSouth,
+    // - is a horizontal pipe connecting west and and.
+    EastWest,
+    // L is a 90-degree bend connecting north and east.
+    NorthEast,
+    // J is a 90-degree bend connecting north and west.
+    NorthWest,
+    // 7 is a 90-degree bend connecting south and west.
+    SouthWest,
+    // F is a 90-degree bend connecting south and east.
+    SouthEast,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = AocError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            b'.' => Self::Empty,
+            b'S' => Self::Start,
+            b'|' => Self::NorthSouth,
+            b'-' => Self::EastWest,
+            b'L' => Self::NorthEast,
+            b'J' => Self::NorthWest,
+            b'7' => Self::SouthWest,
+            b'F' => Self::SouthEast,
+            _ => return Err(AocError::InvalidTile(value as char)),
+        })
+    }
+}
+
+use Direction::*;
+use Tile::*;
+
+impl Tile {
+    pub const PIPES: [Tile; 6] = [
+        NorthSouth, EastWest, NorthEast, NorthWest, SouthWest, SouthEast,
+    ];
+
+    /// The two pipe ends, `None` for ground and the start tile
+    pub fn ends(&self) -> Option<[Direction; 2]> {
+        match self {
+            Start | Empty => None,
+            NorthSouth => Some([North, South]),
+            EastWest => Some([East, West]),
+            NorthEast => Some([North, East]),
+            NorthWest => Some([North, West]),
+            SouthWest => Some([South, West]),
+            SouthEast => Some([South, East]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    pub row_idx: usize,
+    pub col_idx: usize,
+}
+
+impl Coord {
+    pub fn new(row_idx: usize, col_idx: usize) -> Self {
+        Self { row_idx, col_idx }
+    }
+
+    /// Neighbouring coordinate in `direction`, if it is on the map
+    pub fn step(&self, direction: Direction, map: &[Vec<Tile>]) -> Option<Coord> {
+        let height = map.len();
+        let width = map.first().map_or(0, |row| row.len());
+
+        let coord = match direction {
+            North => Coord::new(self.row_idx.checked_sub(1)?, self.col_idx),
+            South => Coord::new(self.row_idx + 1, self.col_idx),
+            West => Coord::new(self.row_idx, self.col_idx.checked_sub(1)?),
+            East => Coord::new(self.row_idx, self.col_idx + 1),
+        };
+        (coord.row_idx < height && coord.col_idx < width).then_some(coord)
+    }
+
+    pub fn tile(&self, map: &[Vec<Tile>]) -> Tile {
+        map[self.row_idx][self.col_idx]
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Vec<Tile>>, Coord), AocError> {
+    let map = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.bytes().map(Tile::try_from).collect())
+        .collect::<Result<Vec<Vec<Tile>>, AocError>>()?;
+
+    let width = map.first().map_or(0, |row| row.len());
+    if let Some(row_idx) = map.iter().position(|row| row.len() != width) {
+        return Err(AocError::RaggedMap {
+            row: row_idx,
+            expected: width,
+            found: map[row_idx].len(),
+        });
+    }
+
+    let start = map
+        .iter()
+        .enumerate()
+        .find_map(|(row_idx, row)| {
+            let col_idx = row.iter().position(|tile| *tile == Start)?;
+            Some(Coord::new(row_idx, col_idx))
+        })
+        .ok_or(AocError::MissingStart)?;
+
+    Ok((map, start))
+}
+
+/// Follows the pipe from `start` heading `first`, returning the tiles in order and the
+/// direction the walk finally enters `start` from, or `None` if the pipe dead-ends
+fn walk(start: Coord, first: Direction, map: &[Vec<Tile>]) -> Option<(Vec<Coord>, Direction)> {
+    let mut coords = vec![start];
+    let mut position = start.step(first, map)?;
+    let mut heading = first;
+
+    loop {
+        let tile = position.tile(map);
+        if tile == Start {
+            return Some((coords, heading.opposite()));
+        }
+
+        let entry = heading.opposite();
+        let [a, b] = tile.ends()?;
+        if entry != a && entry != b {
+            return None;
+        }
+        coords.push(position);
+
+        heading = if entry == a { b } else { a };
+        position = position.step(heading, map)?;
+    }
+}
+
+/// Every pipe shape the start tile could have that closes a loop back to it,
+/// along with that loop in walking order
+pub fn start_candidates(map: &[Vec<Tile>], start: Coord) -> Vec<(Tile, Vec<Coord>)> {
+    Tile::PIPES
+        .into_iter()
+        .filter_map(|shape| {
+            let [out, back] = shape.ends()?;
+            let (coords, entered_from) = walk(start, out, map)?;
+            (entered_from == back).then_some((shape, coords))
+        })
+        .collect()
+}
+
+/// The start tile's pipe shape and the main loop in walking order from `start`,
+/// failing unless exactly one shape closes
+pub fn trace_loop(start: Coord, map: &[Vec<Tile>]) -> Result<(Tile, Vec<Coord>), AocError> {
+    let mut candidates = start_candidates(map, start);
+    match candidates.len() {
+        0 => Err(AocError::NoLoop {
+            row: start.row_idx,
+            col: start.col_idx,
+        }),
+        1 => Ok(candidates.remove(0)),
+        _ => Err(AocError::AmbiguousStart {
+            row: start.row_idx,
+            col: start.col_idx,
+            shapes: candidates
+                .iter()
+                .map(|(shape, _)| format!("{shape:?}"))
+                .collect::<Vec<String>>()
+                .join(", "),
+        }),
+    }
+}
+
+/// replace start with `start_pipe`, and only keep pipe segments that are part of the loop
+pub fn clean_map(
+    start_pipe: Tile,
+    loop_coords: &HashSet<Coord>,
+    map: Vec<Vec<Tile>>,
+) -> Vec<Vec<Tile>> {
+    map.into_iter()
+        .enumerate()
+        .map(|(row_idx, line)| {
+            line.into_iter()
+                .enumerate()
+                .map(|(col_idx, tile)| match tile {
+                    Start => start_pipe,
+                    pipe if loop_coords.contains(&Coord::new(row_idx, col_idx)) => pipe,
+                    _ => Empty,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_square_map() -> miette::Result<()> {
+        // wider than it is tall, with the loop reaching the last column
+        let input = "......
+.S--7.
+.L--J.";
+        let (map, start) = parse(input)?;
+        let (shape, loop_coords) = trace_loop(start, &map)?;
+        assert_eq!(SouthEast, shape);
+        assert_eq!(8, loop_coords.len());
+        assert_eq!(start, loop_coords[0]);
+        assert!(loop_coords
+            .windows(2)
+            .all(
+                |w| w[0].row_idx.abs_diff(w[1].row_idx) + w[0].col_idx.abs_diff(w[1].col_idx) == 1
+            ));
+
+        let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+        let (map, start) = parse(input)?;
+        let (_, loop_coords) = trace_loop(start, &map)?;
+        assert_eq!(16, loop_coords.len());
+        // pipes hanging off the loop aren't part of it
+        let input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+        let (map, start) = parse(input)?;
+        assert_eq!(8, trace_loop(start, &map)?.1.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_start_shape() -> miette::Result<()> {
+        // S touches three pipes, but only east-west closes the loop
+        let input = "..|..
+.FS7.
+.L-J.";
+        let (map, start) = parse(input)?;
+        let (shape, loop_coords) = trace_loop(start, &map)?;
+        assert_eq!(EastWest, shape);
+        assert_eq!(6, loop_coords.len());
+
+        // two separate loops meet at S
+        let input = "..F7.
+.FSJ.
+.LJ..";
+        let (map, start) = parse(input)?;
+        assert!(matches!(
+            trace_loop(start, &map),
+            Err(AocError::AmbiguousStart { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(parse("S-7\n|."), Err(AocError::RaggedMap { .. })));
+        assert!(matches!(parse("F-7\n|X|"), Err(AocError::InvalidTile('X'))));
+        assert!(matches!(parse("F-7\nL-J"), Err(AocError::MissingStart)));
+        let (map, start) = parse("S-7\n|..\nL-.").unwrap();
+        assert!(matches!(
+            trace_loop(start, &map),
+            Err(AocError::NoLoop { .. })
+        ));
+    }
+}
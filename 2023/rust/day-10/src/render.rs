@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::area::inside_tiles;
+use crate::custom_error::AocError;
+use crate::pipes::{clean_map, parse, trace_loop, Coord, Tile};
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_START: &str = "\x1b[1;31m";
+const ANSI_LOOP: &str = "\x1b[33m";
+const ANSI_INSIDE: &str = "\x1b[32m";
+const ANSI_OUTSIDE: &str = "\x1b[2;34m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    /// Pipes drawn as box-drawing glyphs
+    Pipes,
+    /// Loop tiles replaced by their distance from the start along the loop
+    Distance,
+}
+
+impl Tile {
+    pub fn glyph(&self) -> char {
+        match self {
+            Tile::Start => 'S',
+            Tile::Empty => '·',
+            Tile::NorthSouth => '│',
+            Tile::EastWest => '─',
+            Tile::NorthEast => '└',
+            Tile::NorthWest => '┘',
+            Tile::SouthWest => '┐',
+            Tile::SouthEast => '┌',
+        }
+    }
+}
+
+/// Distance of every loop tile from the start, going whichever way round is shorter
+pub fn loop_distances(loop_coords: &[Coord]) -> HashMap<Coord, usize> {
+    let len = loop_coords.len();
+    loop_coords
+        .iter()
+        .enumerate()
+        .map(|(i, coord)| (*coord, i.min(len - i)))
+        .collect()
+}
+
+/// Draws the cleaned map with the loop, inside tiles and outside tiles in different colors
+pub fn render(input: &str, overlay: Overlay) -> Result<String, AocError> {
+    let (map, start) = parse(input)?;
+    let (start_pipe, loop_coords) = trace_loop(start, &map)?;
+    let distances = loop_distances(&loop_coords);
+    let loop_set: HashSet<Coord> = loop_coords.into_iter().collect();
+
+    let map = clean_map(start_pipe, &loop_set, map);
+    let inside = inside_tiles(&map);
+
+    let cell_width = match overlay {
+        Overlay::Pipes => 1,
+        Overlay::Distance => distances
+            .values()
+            .max()
+            .map_or(1, |d| d.to_string().len() + 1),
+    };
+
+    let mut output = String::new();
+    for (row_idx, row) in map.iter().enumerate() {
+        for (col_idx, tile) in row.iter().enumerate() {
+            let coord = Coord::new(row_idx, col_idx);
+            let color = if coord == start {
+                ANSI_START
+            } else if loop_set.contains(&coord) {
+                ANSI_LOOP
+            } else if inside[row_idx][col_idx] {
+                ANSI_INSIDE
+            } else {
+                ANSI_OUTSIDE
+            };
+            let text = match (overlay, distances.get(&coord)) {
+                (Overlay::Distance, Some(distance)) => distance.to_string(),
+                _ => tile.glyph().to_string(),
+            };
+            output.push_str(&format!("{color}{text:>cell_width$}{ANSI_RESET}"));
+        }
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip_ansi(text: &str) -> String {
+        let mut plain = String::new();
+        let mut in_escape = false;
+        for c in text.chars() {
+            match (in_escape, c) {
+                (false, '\x1b') => in_escape = true,
+                (true, 'm') => in_escape = false,
+                (false, c) => plain.push(c),
+                _ => {}
+            }
+        }
+        plain
+    }
+
+    const INPUT: &str = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+    #[test]
+    fn test_render() -> miette::Result<()> {
+        let rendered = render(INPUT, Overlay::Pipes)?;
+        assert_eq!(
+            "·····
+·┌─┐·
+·│·│·
+·└─┘·
+·····
+",
+            strip_ansi(&rendered)
+        );
+        assert!(rendered.contains(&format!("{ANSI_START}┌{ANSI_RESET}")));
+        assert!(rendered.contains(&format!("{ANSI_INSIDE}·{ANSI_RESET}")));
+
+        assert_eq!(
+            " · · · · ·
+ · 0 1 2 ·
+ · 1 · 3 ·
+ · 2 3 4 ·
+ · · · · ·
+",
+            strip_ansi(&render(INPUT, Overlay::Distance)?)
+        );
+        Ok(())
+    }
+}
@@ -7,4 +7,28 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid cell `{0}`, expected `.` or `#`")]
+    #[diagnostic(code(aoc::invalid_cell))]
+    InvalidCell(char),
+
+    #[error("row {row} has {found} cells, expected {expected}")]
+    #[diagnostic(code(aoc::ragged_grid))]
+    RaggedGrid {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("expansion factor {0} would make empty lines vanish, it must be at least 1")]
+    #[diagnostic(code(aoc::invalid_expansion))]
+    InvalidExpansion(u128),
+
+    #[error("there is no galaxy {galaxy}, galaxies are numbered 1 to {count}")]
+    #[diagnostic(code(aoc::unknown_galaxy))]
+    UnknownGalaxy { galaxy: usize, count: usize },
+
+    #[error("{count} galaxies are too many for a distance matrix, the limit is {max}")]
+    #[diagnostic(code(aoc::matrix_too_large))]
+    MatrixTooLarge { count: usize, max: usize },
 }
\ No newline at end of file
@@ -0,0 +1,199 @@
+use crate::custom_error::AocError;
+use crate::universe::{expanded_galaxies, galaxies, parse, Grid, Position};
+
+/// Galaxy count above which the distance matrix is refused
+pub const MAX_MATRIX_GALAXIES: usize = 500;
+
+/// Galaxy with its distance from the one being queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Neighbour {
+    pub galaxy: usize,
+    pub distance: u128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extremes {
+    pub galaxy: usize,
+    pub nearest: Option<Neighbour>,
+    pub farthest: Option<Neighbour>,
+}
+
+/// Galaxies of the expanded universe, numbered from 1 in reading order like the puzzle does
+#[derive(Debug)]
+pub struct ExpandedUniverse {
+    galaxies: Vec<Position>,
+    expanded: Vec<(u128, u128)>,
+    factor: u128,
+}
+
+impl ExpandedUniverse {
+    pub fn new(grid: &Grid, factor: u128) -> Result<Self, AocError> {
+        Ok(ExpandedUniverse {
+            galaxies: galaxies(grid),
+            expanded: expanded_galaxies(grid, factor)?,
+            factor,
+        })
+    }
+
+    pub fn parse(input: &str, factor: u128) -> Result<Self, AocError> {
+        ExpandedUniverse::new(&parse(input)?, factor)
+    }
+
+    pub fn len(&self) -> usize {
+        self.galaxies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.galaxies.is_empty()
+    }
+
+    pub fn factor(&self) -> u128 {
+        self.factor
+    }
+
+    fn index(&self, galaxy: usize) -> Result<usize, AocError> {
+        (1..=self.len())
+            .contains(&galaxy)
+            .then(|| galaxy - 1)
+            .ok_or(AocError::UnknownGalaxy {
+                galaxy,
+                count: self.len(),
+            })
+    }
+
+    /// Position of the galaxy in the unexpanded input
+    pub fn position(&self, galaxy: usize) -> Result<Position, AocError> {
+        Ok(self.galaxies[self.index(galaxy)?])
+    }
+
+    pub fn distance(&self, a: usize, b: usize) -> Result<u128, AocError> {
+        let (ax, ay) = self.expanded[self.index(a)?];
+        let (bx, by) = self.expanded[self.index(b)?];
+        Ok(ax.abs_diff(bx) + ay.abs_diff(by))
+    }
+
+    fn others(&self, galaxy: usize) -> Result<Vec<Neighbour>, AocError> {
+        self.index(galaxy)?;
+        (1..=self.len())
+            .filter(|other| *other != galaxy)
+            .map(|other| {
+                Ok(Neighbour {
+                    galaxy: other,
+                    distance: self.distance(galaxy, other)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Closest other galaxy, the lowest numbered one on ties
+    pub fn nearest(&self, galaxy: usize) -> Result<Option<Neighbour>, AocError> {
+        Ok(self
+            .others(galaxy)?
+            .into_iter()
+            .min_by_key(|n| (n.distance, n.galaxy)))
+    }
+
+    /// Most distant other galaxy, the lowest numbered one on ties
+    pub fn farthest(&self, galaxy: usize) -> Result<Option<Neighbour>, AocError> {
+        Ok(self
+            .others(galaxy)?
+            .into_iter()
+            .max_by_key(|n| (n.distance, std::cmp::Reverse(n.galaxy))))
+    }
+
+    /// Nearest and farthest galaxy for every galaxy in turn
+    pub fn extremes(&self) -> Result<Vec<Extremes>, AocError> {
+        (1..=self.len())
+            .map(|galaxy| {
+                Ok(Extremes {
+                    galaxy,
+                    nearest: self.nearest(galaxy)?,
+                    farthest: self.farthest(galaxy)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Distances between every pair of galaxies as CSV, with galaxy numbers as headers
+    pub fn distance_matrix_csv(&self) -> Result<String, AocError> {
+        if self.len() > MAX_MATRIX_GALAXIES {
+            return Err(AocError::MatrixTooLarge {
+                count: self.len(),
+                max: MAX_MATRIX_GALAXIES,
+            });
+        }
+
+        let mut csv = String::from("galaxy");
+        (1..=self.len()).for_each(|galaxy| csv.push_str(&format!(",{galaxy}")));
+        csv.push('\n');
+
+        for a in 1..=self.len() {
+            csv.push_str(&a.to_string());
+            for b in 1..=self.len() {
+                csv.push_str(&format!(",{}", self.distance(a, b)?));
+            }
+            csv.push('\n');
+        }
+        Ok(csv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_queries() -> miette::Result<()> {
+        let universe = ExpandedUniverse::parse(INPUT, 2)?;
+        assert_eq!(9, universe.len());
+        assert_eq!(9, universe.distance(5, 9)?);
+        assert_eq!(15, universe.distance(1, 7)?);
+        assert_eq!(17, universe.distance(3, 6)?);
+        assert_eq!(5, universe.distance(8, 9)?);
+        assert_eq!(Position::new(3, 0), universe.position(1)?);
+
+        assert_eq!(
+            Some(Neighbour {
+                galaxy: 9,
+                distance: 5
+            }),
+            universe.nearest(8)?
+        );
+        let farthest = universe.farthest(1)?.unwrap();
+        assert!((1..=9).all(|other| universe.distance(1, other).unwrap() <= farthest.distance));
+        assert_eq!(9, universe.extremes()?.len());
+
+        let universe = ExpandedUniverse::parse(INPUT, 10)?;
+        assert_eq!(7 + 2 * 9, universe.distance(5, 9)?);
+        assert!(matches!(
+            universe.distance(0, 1),
+            Err(AocError::UnknownGalaxy { galaxy: 0, .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_matrix_csv() -> miette::Result<()> {
+        let universe = ExpandedUniverse::parse("#.#\n...\n#..", 2)?;
+        assert_eq!(
+            "galaxy,1,2,3
+1,0,3,3
+2,3,0,6
+3,3,6,0
+",
+            universe.distance_matrix_csv()?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::custom_error::AocError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Position { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniversePart {
+    Empty,
+    Galaxy,
+}
+
+pub type Grid = Vec<Vec<UniversePart>>;
+
+pub fn parse(input: &str) -> Result<Grid, AocError> {
+    let grid: Grid = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.bytes()
+                .map(|c| match c {
+                    b'.' => Ok(UniversePart::Empty),
+                    b'#' => Ok(UniversePart::Galaxy),
+                    _ => Err(AocError::InvalidCell(c as char)),
+                })
+                .collect()
+        })
+        .collect::<Result<Grid, AocError>>()?;
+
+    let width = grid.first().map_or(0, |row| row.len());
+    if let Some(row) = grid.iter().position(|line| line.len() != width) {
+        return Err(AocError::RaggedGrid {
+            row,
+            expected: width,
+            found: grid[row].len(),
+        });
+    }
+    Ok(grid)
+}
+
+pub fn galaxies(grid: &[Vec<UniversePart>]) -> Vec<Position> {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .filter_map(move |(x, universe_part)| match universe_part {
+                    UniversePart::Empty => None,
+                    UniversePart::Galaxy => Some(Position::new(x, y)),
+                })
+        })
+        .collect()
+}
+
+fn transpose_grid(input: &Grid) -> Vec<Vec<UniversePart>> {
+    (0..input.first().map_or(0, |row| row.len()))
+        .map(|i| input.iter().map(|c| c[i]).collect())
+        .collect()
+}
+
+pub fn get_empty_cols(grid: &Grid) -> Vec<usize> {
+    get_empty_rows(&transpose_grid(grid))
+}
+
+pub fn get_empty_rows(grid: &Grid) -> Vec<usize> {
+    grid.iter()
+        .enumerate()
+        .filter_map(|(row_num, row)| {
+            if row.iter().all(|&u| u == UniversePart::Empty) {
+                return Some(row_num);
+            }
+            None
+        })
+        .collect()
+}
+
+/// Coordinate after every empty line before it has grown to `factor` lines
+fn expand(coordinate: usize, empty: &[usize], factor: u128) -> u128 {
+    let empty_before = empty.partition_point(|line| *line < coordinate) as u128;
+    coordinate as u128 + empty_before * (factor - 1)
+}
+
+/// Galaxy positions in the expanded universe, in reading order
+pub fn expanded_galaxies(grid: &Grid, factor: u128) -> Result<Vec<(u128, u128)>, AocError> {
+    if factor == 0 {
+        return Err(AocError::InvalidExpansion(factor));
+    }
+    let empty_rows = get_empty_rows(grid);
+    let empty_cols = get_empty_cols(grid);
+
+    Ok(galaxies(grid)
+        .into_iter()
+        .map(|p| {
+            (
+                expand(p.x, &empty_cols, factor),
+                expand(p.y, &empty_rows, factor),
+            )
+        })
+        .collect())
+}
+
+/// Sum of `|a - b|` over every pair of values: once sorted, the i-th value
+/// is the larger one in exactly i pairs
+fn axis_distance_sum(mut values: Vec<u128>) -> u128 {
+    values.sort_unstable();
+    let mut prefix = 0;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let sum = i as u128 * value - prefix;
+            prefix += value;
+            sum
+        })
+        .sum()
+}
+
+/// Sum of the shortest paths between every pair of galaxies once each empty row
+/// and column has grown to `factor` rows or columns
+pub fn run(input: &str, factor: u128) -> Result<u128, AocError> {
+    let grid = parse(input)?;
+    let (xs, ys) = expanded_galaxies(&grid, factor)?.into_iter().unzip();
+
+    Ok(axis_distance_sum(xs) + axis_distance_sum(ys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[rstest]
+    #[case(2, 374)]
+    #[case(10, 1030)]
+    #[case(100, 8410)]
+    fn test_expansion_factor(#[case] factor: u128, #[case] expected: u128) -> miette::Result<()> {
+        assert_eq!(expected, run(INPUT, factor)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_pairwise_sum() -> miette::Result<()> {
+        let positions = expanded_galaxies(&parse(INPUT)?, 7)?;
+        let pairwise: u128 = positions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| positions[i + 1..].iter().map(move |b| (a, b)))
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+        assert_eq!(pairwise, run(INPUT, 7)?);
+
+        assert!(matches!(run(INPUT, 0), Err(AocError::InvalidExpansion(0))));
+        assert!(matches!(
+            parse("#..\n.#\n..#"),
+            Err(AocError::RaggedGrid {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use day_12::nonogram::solve_puzzle;
+use miette::{Context, IntoDiagnostic};
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let path = std::env::args()
+        .nth(1)
+        .ok_or_else(|| miette::miette!("usage: nonogram <puzzle file>"))?;
+    let puzzle = std::fs::read_to_string(&path)
+        .into_diagnostic()
+        .with_context(|| format!("read {path}"))?;
+    print!("{}", solve_puzzle(&puzzle).context("solve nonogram")?);
+    Ok(())
+}
\ No newline at end of file
@@ -7,4 +7,20 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+
+    #[error("invalid spring `{0}`, expected `.`, `#` or `?`")]
+    #[diagnostic(code(aoc::invalid_spring))]
+    InvalidSpring(char),
+
+    #[error("invalid condition record `{0}`")]
+    #[diagnostic(code(aoc::invalid_record))]
+    InvalidRecord(String),
+
+    #[error("invalid nonogram: {0}")]
+    #[diagnostic(code(aoc::invalid_puzzle))]
+    InvalidPuzzle(String),
+
+    #[error("the nonogram has no solution")]
+    #[diagnostic(code(aoc::unsolvable))]
+    Unsolvable,
 }
\ No newline at end of file
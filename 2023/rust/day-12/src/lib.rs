@@ -1,4 +1,6 @@
 pub mod custom_error;
+pub mod nonogram;
+pub mod springs;
 
 pub mod part1;
 pub mod part2;
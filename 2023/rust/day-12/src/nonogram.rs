@@ -0,0 +1,289 @@
+use std::str::FromStr;
+
+use crate::custom_error::AocError;
+use crate::springs::{spring_options, Spring};
+
+/// A picross puzzle: each row and column is a condition record whose damaged springs are
+/// the filled cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nonogram {
+    pub rows: Vec<Vec<usize>>,
+    pub cols: Vec<Vec<usize>>,
+}
+
+pub type Grid = Vec<Vec<Spring>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub grid: Grid,
+    pub unique: bool,
+}
+
+/// Clues one per line, numbers separated by spaces or commas with `0` for an empty line:
+///
+/// ```text
+/// rows
+/// 1 1
+/// 3
+/// columns
+/// 2
+/// 1,1
+/// ```
+impl FromStr for Nonogram {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rows = Vec::new();
+        let mut cols = Vec::new();
+        // whether clues currently go to the rows, `None` before any section header
+        let mut in_rows = None;
+
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line {
+                "rows" => in_rows = Some(true),
+                "columns" | "cols" => in_rows = Some(false),
+                clue => {
+                    let clue = clue
+                        .split([' ', ','])
+                        .filter(|n| !n.is_empty())
+                        .map(|n| n.parse::<usize>())
+                        .filter(|n| *n != Ok(0))
+                        .collect::<Result<Vec<usize>, _>>()
+                        .map_err(|_| AocError::InvalidPuzzle(line.to_string()))?;
+                    match in_rows {
+                        Some(true) => rows.push(clue),
+                        Some(false) => cols.push(clue),
+                        None => return Err(AocError::InvalidPuzzle(line.to_string())),
+                    }
+                }
+            }
+        }
+
+        if rows.is_empty() || cols.is_empty() {
+            return Err(AocError::InvalidPuzzle(
+                "expected both rows and columns".to_string(),
+            ));
+        }
+        Ok(Nonogram { rows, cols })
+    }
+}
+
+/// Fixes every cell of the line that is the same in all arrangements matching the clue,
+/// or `None` if no arrangement matches
+pub fn line_solve(line: &[Spring], clue: &[usize]) -> Option<Vec<Spring>> {
+    let options = spring_options(line, clue)?;
+    Some(
+        line.iter()
+            .zip(options)
+            .map(
+                |(spring, options)| match (options.damaged, options.operational) {
+                    (true, false) => Spring::Damaged,
+                    (false, true) => Spring::Operational,
+                    _ => *spring,
+                },
+            )
+            .collect(),
+    )
+}
+
+impl Nonogram {
+    /// Applies line solving to every row and column until nothing changes,
+    /// returning `None` on a contradiction
+    fn propagate(&self, mut grid: Grid) -> Option<Grid> {
+        loop {
+            let mut changed = false;
+
+            for (row, clue) in self.rows.iter().enumerate() {
+                let solved = line_solve(&grid[row], clue)?;
+                changed |= solved != grid[row];
+                grid[row] = solved;
+            }
+            for (col, clue) in self.cols.iter().enumerate() {
+                let line: Vec<Spring> = grid.iter().map(|row| row[col]).collect();
+                let solved = line_solve(&line, clue)?;
+                if solved != line {
+                    changed = true;
+                    grid.iter_mut()
+                        .zip(solved)
+                        .for_each(|(row, spring)| row[col] = spring);
+                }
+            }
+
+            if !changed {
+                return Some(grid);
+            }
+        }
+    }
+
+    /// Collects up to `limit` solutions, guessing on the first unknown cell when line
+    /// solving stalls
+    fn search(&self, grid: Grid, solutions: &mut Vec<Grid>, limit: usize) {
+        let Some(grid) = self.propagate(grid) else {
+            return;
+        };
+
+        let unknown = grid.iter().enumerate().find_map(|(row, springs)| {
+            let col = springs.iter().position(|s| *s == Spring::Unknown)?;
+            Some((row, col))
+        });
+        let Some((row, col)) = unknown else {
+            solutions.push(grid);
+            return;
+        };
+
+        for guess in [Spring::Damaged, Spring::Operational] {
+            if solutions.len() >= limit {
+                return;
+            }
+            let mut guessed = grid.clone();
+            guessed[row][col] = guess;
+            self.search(guessed, solutions, limit);
+        }
+    }
+
+    pub fn solve(&self) -> Result<Solution, AocError> {
+        let grid = vec![vec![Spring::Unknown; self.cols.len()]; self.rows.len()];
+        let mut solutions = Vec::new();
+        self.search(grid, &mut solutions, 2);
+
+        let unique = solutions.len() == 1;
+        let grid = solutions.into_iter().next().ok_or(AocError::Unsolvable)?;
+        Ok(Solution { grid, unique })
+    }
+}
+
+pub fn render(grid: &Grid) -> String {
+    grid.iter()
+        .map(|row| {
+            row.iter()
+                .map(|spring| match spring {
+                    Spring::Damaged => '█',
+                    Spring::Operational => '·',
+                    Spring::Unknown => '?',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+/// Solves a puzzle given in the text format and renders the grid, noting when other
+/// solutions exist
+pub fn solve_puzzle(input: &str) -> Result<String, AocError> {
+    let solution = input.parse::<Nonogram>()?.solve()?;
+    let mut output = render(&solution.grid);
+    if !solution.unique {
+        output.push_str("(not unique)\n");
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::springs::{count_possible_arangements, format_springs, parse};
+
+    #[test]
+    fn test_line_solve() {
+        let line = vec![Spring::Unknown; 5];
+        assert_eq!("??#??", format_springs(&line_solve(&line, &[3]).unwrap()));
+        assert_eq!(
+            "#.###",
+            format_springs(&line_solve(&line, &[1, 3]).unwrap())
+        );
+        assert_eq!(None, line_solve(&line, &[3, 2]));
+        assert_eq!(Some(vec![Spring::Operational; 5]), line_solve(&line, &[]));
+    }
+
+    /// The cells forced by trying both values for each unknown against the counter
+    fn forced_by_counting(line: &[Spring], clue: &[usize]) -> Option<Vec<Spring>> {
+        let fits = |i: usize, spring| {
+            let mut trial = line.to_vec();
+            trial[i] = spring;
+            count_possible_arangements(trial, clue.to_vec()) > 0
+        };
+        if count_possible_arangements(line.to_vec(), clue.to_vec()) == 0 {
+            return None;
+        }
+        Some(
+            (0..line.len())
+                .map(|i| match line[i] {
+                    Spring::Unknown => {
+                        match (fits(i, Spring::Damaged), fits(i, Spring::Operational)) {
+                            (true, false) => Spring::Damaged,
+                            (false, true) => Spring::Operational,
+                            _ => Spring::Unknown,
+                        }
+                    }
+                    known => known,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_line_solve_matches_counting() -> miette::Result<()> {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+??#???#??.?? 3,2
+#?.?#?? 1,1,2
+?.#.? 2";
+        for record in parse(input) {
+            let (springs, counts) = record?;
+            assert_eq!(
+                forced_by_counting(&springs, &counts),
+                line_solve(&springs, &counts),
+                "{} {counts:?}",
+                format_springs(&springs)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve() -> miette::Result<()> {
+        let heart = "rows
+1 1
+5
+5
+3
+1
+columns
+2
+4
+4
+4
+2";
+        assert_eq!(
+            "·█·█·
+█████
+█████
+·███·
+··█··
+",
+            solve_puzzle(heart)?
+        );
+
+        let diagonal = "rows\n1\n1\ncolumns\n1\n1";
+        let solution = diagonal.parse::<Nonogram>()?.solve()?;
+        assert!(!solution.unique);
+
+        let impossible = "rows\n2\n0\ncolumns\n1\n0";
+        assert!(matches!(
+            impossible.parse::<Nonogram>()?.solve(),
+            Err(AocError::Unsolvable)
+        ));
+        assert!(matches!(
+            "1 1\nrows\n1".parse::<Nonogram>(),
+            Err(AocError::InvalidPuzzle(_))
+        ));
+        Ok(())
+    }
+}
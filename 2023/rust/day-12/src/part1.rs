@@ -1,5 +1,6 @@
 use itertools::Itertools;
-use std::fmt::Display;
+
+use crate::springs::{parse, Spring};
 
 struct Record {
     springs: Vec<Spring>,
@@ -53,42 +54,19 @@ impl Record {
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum Spring {
-    Unknown,
-    Operational,
-    Damaged,
-}
-
-fn parse(input: &str) -> impl Iterator<Item = Record> + '_ {
-    input.lines().map(|line| {
-        let (springs, counts) = line.split_once(' ').unwrap();
-        let springs = springs
-            .chars()
-            .map(|c| match c {
-                '.' => Spring::Operational,
-                '#' => Spring::Damaged,
-                '?' => Spring::Unknown,
-                _ => panic!("Invalid Spring"),
-            })
-            .collect();
-
-        let counts = counts.split(',').map(|s| s.parse().unwrap()).collect();
-
-        Record { springs, counts }
-    })
-}
-
-pub fn run(input: &str) -> impl Display {
+pub fn run(input: &str) -> Result<usize, AocError> {
     parse(input)
-        .map(|record| record.valid_arrangements())
-        .sum::<usize>()
+        .map(|record| {
+            let (springs, counts) = record?;
+            Ok(Record { springs, counts }.valid_arrangements())
+        })
+        .sum()
 }
 
 use crate::custom_error::AocError;
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    Ok(run(input).to_string())
+    Ok(run(input)?.to_string())
 }
 
 #[cfg(test)]
@@ -1,80 +1,9 @@
-use std::fmt::Display;
+use crate::springs::{count_possible_arangements, parse, Spring};
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum Spring {
-    Unknown,
-    Operational,
-    Damaged,
-}
-
-fn parse(input: &str) -> impl Iterator<Item = (Vec<Spring>, Vec<usize>)> + '_ {
-    input.lines().map(|line| {
-        let (springs, counts) = line.split_once(' ').unwrap();
-        let springs: Vec<Spring> = springs
-            .chars()
-            .map(|c| match c {
-                '.' => Spring::Operational,
-                '#' => Spring::Damaged,
-                '?' => Spring::Unknown,
-                _ => panic!("at the disco"),
-            })
-            .collect();
-        let counts: Vec<usize> = counts.split(',').filter_map(|s| s.parse().ok()).collect();
-
-        (springs, counts)
-    })
-}
-
-fn count_possible_arangements(mut springs: Vec<Spring>, counts: Vec<usize>) -> u64 {
-    springs.push(Spring::Operational);
-    let mut cache = vec![vec![None; springs.len()]; counts.len()];
-    count_possible_arangements_inner(&springs, &counts, &mut cache)
-}
-
-fn count_possible_arangements_inner(
-    springs: &[Spring],
-    counts: &[usize],
-    cache: &mut [Vec<Option<u64>>],
-) -> u64 {
-    if counts.is_empty() {
-        return if springs.contains(&Spring::Damaged) {
-            // Too many previous unknowns were counted as damaged
-            0
-        } else {
-            // All remaining unknowns are operational
-            1
-        };
-    }
-
-    if springs.len() < counts.iter().sum::<usize>() + counts.len() {
-        // Not enough space for remaining numbers
-        return 0;
-    }
-
-    if let Some(cached) = cache[counts.len() - 1][springs.len() - 1] {
-        return cached;
-    }
-
-    let mut arangements = 0;
-    if springs[0] != Spring::Damaged {
-        // Assume operational
-        arangements += count_possible_arangements_inner(&springs[1..], counts, cache);
-    }
-    let next_group_size = counts[0];
-    if !springs[..next_group_size].contains(&Spring::Operational)
-        && springs[next_group_size] != Spring::Damaged
-    {
-        // Assume damaged
-        arangements +=
-            count_possible_arangements_inner(&springs[next_group_size + 1..], &counts[1..], cache);
-    }
-    cache[counts.len() - 1][springs.len() - 1] = Some(arangements);
-    arangements
-}
-
-pub fn run(input: &str) -> impl Display {
+pub fn run(input: &str) -> Result<u64, AocError> {
     parse(input)
-        .map(|(mut springs, mut counts)| {
+        .map(|record| {
+            let (mut springs, mut counts) = record?;
             springs = springs
                 .iter()
                 .copied()
@@ -89,15 +18,15 @@ pub fn run(input: &str) -> impl Display {
                 .take(counts.len() * 5)
                 .collect();
 
-            count_possible_arangements(springs, counts)
+            Ok(count_possible_arangements(springs, counts))
         })
-        .sum::<u64>()
+        .sum()
 }
 
 use crate::custom_error::AocError;
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    Ok(run(input).to_string())
+    Ok(run(input)?.to_string())
 }
 
 #[cfg(test)]
@@ -0,0 +1,410 @@
+use std::fmt;
+
+use crate::custom_error::AocError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Spring {
+    Unknown,
+    Operational,
+    Damaged,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(AocError::InvalidSpring(c)),
+        }
+    }
+}
+
+impl fmt::Display for Spring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Spring::Unknown => '?',
+            Spring::Operational => '.',
+            Spring::Damaged => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+pub fn format_springs(springs: &[Spring]) -> String {
+    springs.iter().map(|spring| spring.to_string()).collect()
+}
+
+pub fn parse_record(line: &str) -> Result<(Vec<Spring>, Vec<usize>), AocError> {
+    let invalid = || AocError::InvalidRecord(line.to_string());
+    let (springs, counts) = line.trim().split_once(' ').ok_or_else(invalid)?;
+    let springs = springs
+        .chars()
+        .map(Spring::try_from)
+        .collect::<Result<Vec<Spring>, AocError>>()?;
+    let counts = counts
+        .split(',')
+        .map(|s| s.parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<usize>, AocError>>()?;
+
+    Ok((springs, counts))
+}
+
+pub fn parse(
+    input: &str,
+) -> impl Iterator<Item = Result<(Vec<Spring>, Vec<usize>), AocError>> + '_ {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_record)
+}
+
+/// Memo of arrangement counts, keyed by how many counts and springs are left
+pub type Cache = Vec<Vec<Option<u64>>>;
+
+fn new_cache(springs: &[Spring], counts: &[usize]) -> Cache {
+    vec![vec![None; springs.len()]; counts.len()]
+}
+
+/// Whether the first spring can be operational
+fn fits_operational(springs: &[Spring]) -> bool {
+    springs[0] != Spring::Damaged
+}
+
+/// Whether a damaged group of `size` can start at the first spring, followed by an operational one
+fn fits_group(springs: &[Spring], size: usize) -> bool {
+    !springs[..size].contains(&Spring::Operational) && springs[size] != Spring::Damaged
+}
+
+pub fn count_possible_arangements(mut springs: Vec<Spring>, counts: Vec<usize>) -> u64 {
+    springs.push(Spring::Operational);
+    let mut cache = new_cache(&springs, &counts);
+    count_possible_arangements_inner(&springs, &counts, &mut cache)
+}
+
+/// `springs` must end with an operational spring so every group has one after it
+pub fn count_possible_arangements_inner(
+    springs: &[Spring],
+    counts: &[usize],
+    cache: &mut [Vec<Option<u64>>],
+) -> u64 {
+    if counts.is_empty() {
+        return if springs.contains(&Spring::Damaged) {
+            // Too many previous unknowns were counted as damaged
+            0
+        } else {
+            // All remaining unknowns are operational
+            1
+        };
+    }
+
+    if springs.len() < counts.iter().sum::<usize>() + counts.len() {
+        // Not enough space for remaining numbers
+        return 0;
+    }
+
+    if let Some(cached) = cache[counts.len() - 1][springs.len() - 1] {
+        return cached;
+    }
+
+    let mut arangements = 0;
+    if fits_operational(springs) {
+        // Assume operational
+        arangements += count_possible_arangements_inner(&springs[1..], counts, cache);
+    }
+    let next_group_size = counts[0];
+    if fits_group(springs, next_group_size) {
+        // Assume damaged
+        arangements +=
+            count_possible_arangements_inner(&springs[next_group_size + 1..], &counts[1..], cache);
+    }
+    cache[counts.len() - 1][springs.len() - 1] = Some(arangements);
+    arangements
+}
+
+/// Which states a spring takes across every arrangement of its row
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpringOptions {
+    pub operational: bool,
+    pub damaged: bool,
+}
+
+/// The options of every spring in the row, or `None` when no arrangement fits.
+/// A forward pass counts the ways to fill each prefix and the memoized counter
+/// each suffix, so a choice is possible when both sides of it have a way.
+pub fn spring_options(springs: &[Spring], counts: &[usize]) -> Option<Vec<SpringOptions>> {
+    let mut springs = springs.to_vec();
+    springs.push(Spring::Operational);
+    let len = springs.len();
+    let mut cache = new_cache(&springs, counts);
+    let mut behind = |position: usize, group: usize| {
+        count_possible_arangements_inner(&springs[position..], &counts[group..], &mut cache)
+    };
+    if behind(0, 0) == 0 {
+        return None;
+    }
+
+    // the padding spring at the end takes the options past the row
+    let mut options = vec![SpringOptions::default(); len + 1];
+    // +1 where a possible group starts and -1 where it ends
+    let mut damaged_edges = vec![0_i64; len + 1];
+    let mut ahead = vec![vec![0_u64; counts.len() + 1]; len + 1];
+    ahead[0][0] = 1;
+
+    for position in 0..len {
+        let rest = &springs[position..];
+        for group in 0..=counts.len() {
+            let ways = ahead[position][group];
+            if ways == 0 {
+                continue;
+            }
+
+            if fits_operational(rest) {
+                ahead[position + 1][group] = ahead[position + 1][group].saturating_add(ways);
+                if behind(position + 1, group) > 0 {
+                    options[position].operational = true;
+                }
+            }
+
+            let Some(&size) = counts.get(group) else {
+                continue;
+            };
+            if position + size < len && fits_group(rest, size) {
+                let next = position + size + 1;
+                ahead[next][group + 1] = ahead[next][group + 1].saturating_add(ways);
+                if behind(next, group + 1) > 0 {
+                    damaged_edges[position] += 1;
+                    damaged_edges[position + size] -= 1;
+                    options[position + size].operational = true;
+                }
+            }
+        }
+    }
+
+    let mut covered = 0;
+    options.truncate(len - 1);
+    for (option, edge) in options.iter_mut().zip(damaged_edges) {
+        covered += edge;
+        option.damaged = covered > 0;
+    }
+    Some(options)
+}
+
+#[derive(Debug, Clone)]
+struct Frame {
+    position: usize,
+    group: usize,
+    prefix: Vec<Spring>,
+}
+
+/// Every concrete arrangement of a row, produced lazily with operational springs tried
+/// before damaged ones. Branches the counter says are empty are never entered, and
+/// `nth` (and so `skip`) jumps over whole branches by their count.
+#[derive(Debug)]
+pub struct Arrangements {
+    springs: Vec<Spring>,
+    counts: Vec<usize>,
+    cache: Cache,
+    stack: Vec<Frame>,
+}
+
+impl Arrangements {
+    pub fn new(springs: &[Spring], counts: &[usize]) -> Self {
+        let mut springs = springs.to_vec();
+        springs.push(Spring::Operational);
+        let mut arrangements = Arrangements {
+            cache: new_cache(&springs, counts),
+            springs,
+            counts: counts.to_vec(),
+            stack: Vec::new(),
+        };
+        arrangements.push(Frame {
+            position: 0,
+            group: 0,
+            prefix: Vec::new(),
+        });
+        arrangements
+    }
+
+    fn count(&mut self, frame: &Frame) -> u64 {
+        count_possible_arangements_inner(
+            &self.springs[frame.position..],
+            &self.counts[frame.group..],
+            &mut self.cache,
+        )
+    }
+
+    fn push(&mut self, frame: Frame) {
+        if self.count(&frame) > 0 {
+            self.stack.push(frame);
+        }
+    }
+
+    /// Either the finished arrangement, or the frame's children pushed onto the stack
+    fn expand(&mut self, frame: Frame) -> Option<Vec<Spring>> {
+        let len = self.springs.len() - 1;
+        if frame.group == self.counts.len() {
+            let mut springs = frame.prefix;
+            springs.resize(len, Spring::Operational);
+            return Some(springs);
+        }
+
+        let rest = &self.springs[frame.position..];
+        let size = self.counts[frame.group];
+        let (group_fits, operational_fits) = (fits_group(rest, size), fits_operational(rest));
+        // pushed first so it is popped after the operational branch
+        if group_fits {
+            let mut prefix = frame.prefix.clone();
+            prefix.extend(std::iter::repeat_n(Spring::Damaged, size));
+            prefix.push(Spring::Operational);
+            self.push(Frame {
+                position: frame.position + size + 1,
+                group: frame.group + 1,
+                prefix,
+            });
+        }
+        if operational_fits {
+            let mut prefix = frame.prefix;
+            prefix.push(Spring::Operational);
+            self.push(Frame {
+                position: frame.position + 1,
+                group: frame.group,
+                prefix,
+            });
+        }
+        None
+    }
+}
+
+impl Iterator for Arrangements {
+    type Item = Vec<Spring>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.pop() {
+            if let Some(springs) = self.expand(frame) {
+                return Some(springs);
+            }
+        }
+        None
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.pop() {
+            let count = self.count(&frame);
+            if count <= n as u64 {
+                n -= count as usize;
+                continue;
+            }
+            if let Some(springs) = self.expand(frame) {
+                return Some(springs);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(springs: &[Spring], counts: &[usize]) -> u64 {
+        let unknowns: Vec<usize> = (0..springs.len())
+            .filter(|i| springs[*i] == Spring::Unknown)
+            .collect();
+        (0..1_u64 << unknowns.len())
+            .filter(|mask| {
+                let mut row = springs.to_vec();
+                for (bit, i) in unknowns.iter().enumerate() {
+                    row[*i] = if mask >> bit & 1 == 1 {
+                        Spring::Damaged
+                    } else {
+                        Spring::Operational
+                    };
+                }
+                format_springs(&row)
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(str::len)
+                    .eq(counts.iter().copied())
+            })
+            .count() as u64
+    }
+
+    const INPUT: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn test_arrangements_match_counts() -> miette::Result<()> {
+        for record in parse(INPUT) {
+            let (springs, counts) = record?;
+            let all: Vec<Vec<Spring>> = Arrangements::new(&springs, &counts).collect();
+            let count = count_possible_arangements(springs.clone(), counts.clone());
+
+            assert_eq!(count, all.len() as u64);
+            assert_eq!(brute_force(&springs, &counts), count);
+            for arrangement in &all {
+                assert_eq!(springs.len(), arrangement.len());
+                assert!(springs
+                    .iter()
+                    .zip(arrangement)
+                    .all(|(given, chosen)| *given == Spring::Unknown || given == chosen));
+                assert_eq!(1, brute_force(arrangement, &counts));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_spring_options_match_brute_force() -> miette::Result<()> {
+        let extra = "??#???#??.?? 3,2\n#?.?#?? 1,1,2\n?.#.? 2\n????? 0";
+        for record in parse(INPUT).chain(parse(extra)) {
+            let (springs, counts) = record?;
+            let counts: Vec<usize> = counts.into_iter().filter(|c| *c > 0).collect();
+            let options = spring_options(&springs, &counts);
+            if brute_force(&springs, &counts) == 0 {
+                assert_eq!(None, options);
+                continue;
+            }
+
+            let fits = |i: usize, spring| {
+                let mut trial = springs.clone();
+                trial[i] = spring;
+                brute_force(&trial, &counts) > 0
+            };
+            let expected: Vec<SpringOptions> = (0..springs.len())
+                .map(|i| SpringOptions {
+                    operational: springs[i] != Spring::Damaged && fits(i, Spring::Operational),
+                    damaged: springs[i] != Spring::Operational && fits(i, Spring::Damaged),
+                })
+                .collect();
+            assert_eq!(Some(expected), options, "{}", format_springs(&springs));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_order_and_skip() -> miette::Result<()> {
+        let (springs, counts) = parse_record("?###???????? 3,2,1")?;
+        let all: Vec<String> = Arrangements::new(&springs, &counts)
+            .map(|springs| format_springs(&springs))
+            .collect();
+        // operational springs sort first, so groups start as far right as they can
+        assert_eq!(".###....##.#", all[0]);
+        assert_eq!(".###.##.#...", all[9]);
+
+        let skipped: Vec<String> = Arrangements::new(&springs, &counts)
+            .skip(3)
+            .take(4)
+            .map(|springs| format_springs(&springs))
+            .collect();
+        assert_eq!(all[3..7], skipped[..]);
+        assert_eq!(None, Arrangements::new(&springs, &counts).nth(10));
+        Ok(())
+    }
+}